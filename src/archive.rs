@@ -2,40 +2,111 @@ use crate::config_file::*;
 use crate::ctx;
 use crate::package::Package;
 use crate::Client;
-use anyhow::{Context, Result};
-use flate2::bufread::GzDecoder;
-use serde::Serialize;
+use anyhow::{anyhow, Context, Result};
+use bzip2::read::BzDecoder;
+use flate2::read::GzDecoder;
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
 use std::fmt::Display;
-use std::fs::{create_dir_all, set_permissions, File, Permissions};
-use std::io::{self, prelude::*, Cursor};
-use std::path::{Component::Normal, Path, PathBuf};
+use std::fs::{create_dir_all, hard_link, set_permissions, File, Permissions};
+use std::hash::{Hash, Hasher};
+use std::io::{self, prelude::*, BufReader, Cursor};
+use std::path::{
+    Component::{CurDir, Normal, ParentDir},
+    Path, PathBuf,
+};
+use std::sync::Arc;
 use tar::Archive as Tarchive;
-use tar::EntryType::Directory;
+use tar::EntryType::{Directory, Link, Symlink};
+use tempfile::{NamedTempFile, TempPath};
+use xz2::read::XzDecoder;
 use zip::result::{ZipError, ZipResult};
 use zip::ZipArchive as Zarchive;
 
-type TArch = Tarchive<GzDecoder<Cursor<Vec<u8>>>>;
-type ZArch = Zarchive<Cursor<Vec<u8>>>;
+type TArch = Tarchive<Box<dyn Read + Send>>;
+type ZArch = Zarchive<Box<dyn ReadSeek>>;
 
+trait ReadSeek: Read + Seek + Send {}
+impl<T: Read + Seek + Send> ReadSeek for T {}
+
+/// A downloaded archive on disk. Its a temporary file, deleted when the last clone is dropped.
+#[derive(Clone, Debug)]
+pub struct ArchiveFile(Arc<TempPath>);
+
+impl ArchiveFile {
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl From<NamedTempFile> for ArchiveFile {
+    fn from(f: NamedTempFile) -> Self {
+        Self(Arc::new(f.into_temp_path()))
+    }
+}
+
+impl PartialEq for ArchiveFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.path() == other.path()
+    }
+}
+impl Eq for ArchiveFile {}
+impl PartialOrd for ArchiveFile {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for ArchiveFile {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.path().cmp(other.path())
+    }
+}
+impl Hash for ArchiveFile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path().hash(state)
+    }
+}
+
+/// A archive: its uri, and where to read it from. Cheap to clone, the bytes are shared.
 #[derive(Default, Clone, Serialize, PartialEq, Eq, Ord, PartialOrd, Hash, Debug)]
 pub struct Data {
     #[serde(skip)]
-    pub bytes: Vec<u8>,
+    pub bytes: Arc<[u8]>,
+    /// If set, the archive is read from this file instead of `bytes`.
+    #[serde(skip)]
+    pub file: Option<ArchiveFile>,
     pub uri: String,
 }
 
 impl Data {
     pub fn new(bytes: Vec<u8>, uri: String) -> Self {
-        Self { bytes, uri }
-    }
-    pub fn new_bytes(bytes: Vec<u8>) -> Self {
         Self {
-            bytes,
-            uri: String::new(),
+            bytes: bytes.into(),
+            uri,
+            ..Default::default()
         }
     }
     pub fn new_uri(uri: String) -> Self {
-        Self { bytes: vec![], uri }
+        Self {
+            uri,
+            ..Default::default()
+        }
+    }
+    pub fn new_file(file: ArchiveFile, uri: String) -> Self {
+        Self {
+            file: Some(file),
+            uri,
+            ..Default::default()
+        }
+    }
+
+    fn reader(self) -> io::Result<Box<dyn ReadSeek>> {
+        Ok(match self.file {
+            Some(f) => Box::new(BufReader::new(File::open(f.path())?)),
+            None => Box::new(Cursor::new(self.bytes)),
+        })
     }
 }
 
@@ -44,6 +115,15 @@ impl Data {
 pub enum CompressionType {
     Gzip(Data),
     Zip(Data),
+    Xz(Data),
+    Bzip2(Data),
+    Zstd(Data),
+    /// A uncompressed tarball.
+    Tar(Data),
+    /// A git repository, pinned to a commit. `file` holds the archive made when resolving it (see [crate::git::GitRepo::archive]).
+    Git(Data),
+    /// A local directory (`file:../my-addon`). Copied (or symlinked) instead of downloaded.
+    Local(Data),
     Lock(String),
     #[default]
     None,
@@ -52,41 +132,96 @@ pub enum CompressionType {
 impl Display for CompressionType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CompressionType::Gzip(d) => write!(f, "{}", d.uri),
-            CompressionType::Zip(d) => write!(f, "{}", d.uri),
             CompressionType::Lock(d) => write!(f, "{}", d),
-            _ => unreachable!(),
+            _ => write!(f, "{}", self.data().unwrap().uri),
         }
     }
 }
 
 impl CompressionType {
-    pub fn from(ty: &str, bytes: Vec<u8>, uri: String) -> Self {
-        match ty {
-            "zip" => Self::Zip(Data::new(bytes, uri)),
-            _ => Self::Gzip(Data::new(bytes, uri)),
+    /// Figures out the type of a archive from its magic bytes (the uri can lie).
+    pub fn from(bytes: Vec<u8>, uri: String) -> Result<Self> {
+        Ok(Self::detect(&bytes, &uri)?(Data::new(bytes, uri)))
+    }
+
+    /// Like [CompressionType::from], but for a downloaded archive, which is kept on disk (and never read into memory).
+    pub fn from_file(file: impl Into<ArchiveFile>, uri: String) -> Result<Self> {
+        let file = file.into();
+        let mut head = Vec::with_capacity(262);
+        ctx!(
+            File::open(file.path()).and_then(|f| f.take(262).read_to_end(&mut head)),
+            "reading {}",
+            file.path().display()
+        )?;
+        Ok(Self::detect(&head, &uri)?(Data::new_file(file, uri)))
+    }
+
+    /// Picks the variant from the first 262 bytes of a archive.
+    fn detect(head: &[u8], uri: &str) -> Result<fn(Data) -> Self> {
+        let starts = |magic: &[u8]| head.starts_with(magic);
+        Ok(if starts(&[0x1f, 0x8b]) {
+            Self::Gzip
+        } else if starts(b"PK\x03\x04") || starts(b"PK\x05\x06") {
+            Self::Zip
+        } else if starts(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+            Self::Xz
+        } else if starts(b"BZh") {
+            Self::Bzip2
+        } else if starts(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Self::Zstd
+        } else if head.get(257..262) == Some(b"ustar") {
+            Self::Tar
+        } else {
+            return Err(anyhow!(
+                "{uri} is not a supported archive (expected zip, tar, tar.gz, tar.xz, tar.bz2 or tar.zst)"
+            ));
+        })
+    }
+
+    pub fn data(&self) -> Option<&Data> {
+        match self {
+            CompressionType::Gzip(d)
+            | CompressionType::Zip(d)
+            | CompressionType::Xz(d)
+            | CompressionType::Bzip2(d)
+            | CompressionType::Zstd(d)
+            | CompressionType::Tar(d)
+            | CompressionType::Git(d)
+            | CompressionType::Local(d) => Some(d),
+            _ => None,
         }
     }
 
     pub fn lock(&mut self) {
-        *self = Self::Lock(match self {
-            CompressionType::Gzip(d) => std::mem::take(&mut d.uri),
-            CompressionType::Zip(d) => std::mem::take(&mut d.uri),
-            _ => unreachable!(),
-        })
+        *self = Self::Lock(self.data().expect("Should not lock twice").uri.clone())
     }
 }
 
 enum ArchiveType {
     Gzip(Box<TArch>),
+    Xz(Box<TArch>),
+    Bzip2(Box<TArch>),
+    Zstd(Box<TArch>),
+    Tar(Box<TArch>),
     Zip(ZArch),
 }
 
+/// A opened archive, with its entries listed.
+/// Tarballs cant be rewound, so they are read twice: once when opening, and once when unpacking.
 pub struct Archive {
-    inner: ArchiveType,
+    source: CompressionType,
     uri: String,
+    /// The entries, as they are in the archive.
+    raw: Vec<PathBuf>,
+    /// The contents of the [LISTED_FILES], by their path in the archive.
+    listed: Vec<(PathBuf, String)>,
+    /// The directory every entry is in (like npm's `package/`), which gets skipped. See [shared_toplevel].
+    toplevel: Option<PathBuf>,
 }
 
+/// The (small) files that are read while listing a archive, see [Archive::file].
+const LISTED_FILES: [&str; 2] = ["package.json", ".npmignore"];
+
 // impl<'a, Z> From<TArch<'a>> for Archive<'a> {
 //     fn from(value: TArch<'a>) -> Self {
 //         Self::Gzip(value)
@@ -99,18 +234,37 @@ pub struct Archive {
 //     }
 // }
 
-fn unpack_zarchive(archive: &mut ZArch, dst: &Path) -> ZipResult<()> {
+/// Unpacks a zip. Returns the number of unpacked entries.
+fn unpack_zarchive(
+    archive: &mut ZArch,
+    dst: &Path,
+    toplevel: Option<&Path>,
+    root: Option<&Path>,
+    filter: &Filter,
+) -> ZipResult<usize> {
     if dst.symlink_metadata().is_err() {
         create_dir_all(dst).map_err(ZipError::Io)?;
     }
     let dst = &dst.canonicalize().unwrap_or(dst.to_path_buf());
 
     let mut directories = vec![];
+    let mut unpacked = 0;
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)?;
-        let path = dst.join(skip_toplevel(
-            file.enclosed_name().ok_or(ZipError::FileNotFound)?,
-        ));
+        let raw = file
+            .enclosed_name()
+            .ok_or_else(|| escape(Path::new(file.name())))?
+            .to_path_buf();
+        let archive_path = skip_toplevel(&raw, toplevel);
+        let Some(path) = select(&archive_path, root) else {
+            continue;
+        };
+        if !file.is_dir() && !filter.includes(&archive_path, &path) {
+            continue;
+        }
+        let path = dst.join(path);
+        check_inside(&path, dst)?;
+        unpacked += 1;
         if file.is_dir() {
             directories.push(path);
         } else {
@@ -129,17 +283,248 @@ fn unpack_zarchive(archive: &mut ZArch, dst: &Path) -> ZipResult<()> {
     for path in directories {
         create_dir_all(path)?;
     }
-    Ok(())
+    Ok(unpacked)
 }
 
-fn skip_toplevel(p: &Path) -> PathBuf {
-    p.components()
-        .skip(1)
+fn skip_toplevel(p: &Path, toplevel: Option<&Path>) -> PathBuf {
+    toplevel
+        .and_then(|t| p.strip_prefix(t).ok())
+        .unwrap_or(p)
+        .components()
         .filter(|c| matches!(c, Normal(_)))
         .collect::<PathBuf>()
 }
 
-fn unpack_tarchive(archive: &mut TArch, dst: &Path) -> io::Result<()> {
+/// The directory every one of `paths` is in, if there is one.
+/// Not `addons`, as thats a project without a wrapping directory (`addons/x/plugin.cfg`).
+fn shared_toplevel(paths: &[PathBuf]) -> Option<PathBuf> {
+    let first = |p: &PathBuf| {
+        p.components()
+            .find(|c| matches!(c, Normal(_)))
+            .map(|c| PathBuf::from(c.as_os_str()))
+    };
+    let top = first(paths.first()?)?;
+    let shared = paths.iter().all(|p| first(p).as_ref() == Some(&top))
+        // a lone file is not a directory
+        && paths.iter().any(|p| skip_toplevel(p, Some(&top)).as_os_str() != "");
+    (shared && top != Path::new("addons")).then_some(top)
+}
+
+/// Strips `root` from `p`, or [None] if `p` is not inside `root`.
+fn select(p: &Path, root: Option<&Path>) -> Option<PathBuf> {
+    match root {
+        Some(root) => p.strip_prefix(root).ok().map(Path::to_path_buf),
+        None => Some(p.to_path_buf()),
+    }
+}
+
+/// Finds the `addons/<name>` directory of an archive (with the toplevel skipped), if there is exactly one.
+/// Used for archives that contain a whole project (like asset library zips), rather than just the addon.
+pub fn find_addon_root(paths: &[PathBuf]) -> Option<PathBuf> {
+    let mut roots = paths
+        .iter()
+        .filter(|p| p.starts_with("addons") && p.components().count() > 2)
+        .map(|p| p.components().take(2).collect::<PathBuf>())
+        .collect::<Vec<_>>();
+    roots.dedup();
+    match roots.as_slice() {
+        [root] => Some(root.clone()),
+        _ => None,
+    }
+}
+
+/// Like [find_addon_root], but only if the addon is a editor plugin (has a `plugin.cfg`).
+pub fn find_plugin_root(paths: &[PathBuf]) -> Option<PathBuf> {
+    let root = find_addon_root(paths)?;
+    paths.contains(&root.join("plugin.cfg")).then_some(root)
+}
+
+/// Decides which files of a archive get installed.
+/// - package.json's `files` allowlist (package.json, readmes and licenses are always included)
+/// - .npmignore (if there is no `files`)
+/// - directories with a .gdignore (godot ignores them anyways)
+/// - the `exclude` list of the dependency (relative to the installed package)
+#[derive(Default, Debug)]
+pub struct Filter {
+    files: Option<Vec<Pattern>>,
+    /// The .npmignore, in order. `!` patterns (`true`) include what earlier ones ignored.
+    ignore: Vec<(Pattern, bool)>,
+    gdignored: Vec<PathBuf>,
+    exclude: Vec<Pattern>,
+}
+
+impl Filter {
+    /// `paths` are the paths of the archive, see [Archive::paths].
+    pub fn new(
+        paths: &[PathBuf],
+        package_json: Option<&str>,
+        npmignore: Option<&str>,
+        exclude: &[String],
+    ) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Files {
+            files: Option<Vec<String>>,
+        }
+        fn patterns<'a>(p: impl IntoIterator<Item = &'a str>) -> Result<Vec<Pattern>> {
+            p.into_iter()
+                .map(|p| p.trim().trim_start_matches('/').trim_end_matches('/'))
+                .filter(|p| !p.is_empty() && !p.starts_with('#') && !p.starts_with('!'))
+                .map(|p| ctx!(Pattern::new(p), "parsing glob {p}"))
+                .collect()
+        }
+        fn ignore_patterns(npmignore: &str) -> Result<Vec<(Pattern, bool)>> {
+            npmignore
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.starts_with('#'))
+                .map(|l| match l.strip_prefix('!') {
+                    Some(l) => (l, true),
+                    None => (l, false),
+                })
+                .map(|(l, negated)| (l.trim_start_matches('/').trim_end_matches('/'), negated))
+                .filter(|(l, _)| !l.is_empty())
+                .map(|(l, negated)| Ok((ctx!(Pattern::new(l), "parsing glob {l}")?, negated)))
+                .collect()
+        }
+        let files = match package_json.map(deser_hjson::from_str::<Files>) {
+            Some(Ok(Files { files: Some(f) })) => Some(patterns(f.iter().map(String::as_str))?),
+            _ => None,
+        };
+        Ok(Self {
+            ignore: match (&files, npmignore) {
+                (None, Some(i)) => ignore_patterns(i)?,
+                _ => vec![],
+            },
+            files,
+            gdignored: paths
+                .iter()
+                .filter(|p| p.file_name().is_some_and(|f| f == ".gdignore"))
+                .filter_map(|p| p.parent().map(Path::to_path_buf))
+                .collect(),
+            exclude: patterns(exclude.iter().map(String::as_str))?,
+        })
+    }
+
+    /// Does `p`, or one of its parents, match one of `patterns`?
+    fn matches(patterns: &[Pattern], p: &Path) -> bool {
+        p.ancestors()
+            .filter(|a| !a.as_os_str().is_empty())
+            .any(|a| patterns.iter().any(|pat| pat.matches_path(a)))
+    }
+
+    /// Does the .npmignore ignore `p`? The last pattern that matches decides.
+    fn ignored(&self, p: &Path) -> bool {
+        self.ignore
+            .iter()
+            .rev()
+            .find(|(pat, _)| Self::matches(std::slice::from_ref(pat), p))
+            .is_some_and(|(_, negated)| !negated)
+    }
+
+    /// Should this file be installed? `path` is relative to the archive, `installed` to the package.
+    pub fn includes(&self, path: &Path, installed: &Path) -> bool {
+        if let Some(files) = &self.files {
+            let always = path.components().count() == 1
+                && path.to_str().is_some_and(|p| {
+                    let p = p.to_lowercase();
+                    p == "package.json" || p.starts_with("readme") || p.starts_with("license")
+                });
+            if !always && !Self::matches(files, path) {
+                return false;
+            }
+        }
+        !self.ignored(path)
+            && !self.gdignored.iter().any(|d| path.starts_with(d))
+            && !Self::matches(&self.exclude, installed)
+    }
+}
+
+fn escape(p: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "archive entry {} points outside of the package",
+            p.display()
+        ),
+    )
+}
+
+/// Rejects archive paths that are absolute or contain `..`.
+fn check_path(p: &Path) -> io::Result<()> {
+    if p.components().all(|c| matches!(c, Normal(_) | CurDir)) {
+        Ok(())
+    } else {
+        Err(escape(p))
+    }
+}
+
+/// Makes sure that `p` is inside `dst`, even if it goes through a symlink extracted earlier.
+/// `dst` must be canonical.
+fn check_inside(p: &Path, dst: &Path) -> io::Result<()> {
+    let mut existing = p;
+    while existing.symlink_metadata().is_err() {
+        existing = existing.parent().ok_or_else(|| escape(p))?;
+    }
+    match existing.canonicalize() {
+        Ok(real) if real.starts_with(dst) => Ok(()),
+        _ => Err(escape(p)),
+    }
+}
+
+/// Lexically resolves a symlink `target` in `dir`, or [None] if it leaves the package.
+fn resolve_link(dir: &Path, target: &Path) -> Option<PathBuf> {
+    let mut resolved = dir.to_path_buf();
+    for c in target.components() {
+        match c {
+            Normal(n) => resolved.push(n),
+            CurDir => {}
+            ParentDir if resolved.pop() => {}
+            _ => return None,
+        }
+    }
+    Some(resolved)
+}
+
+/// Resolves `p` (relative to `dst`) like the os would, but following links by reading them,
+/// so dangling links (which cant be canonicalized) are checked too. [None] if it leaves `dst` (or loops).
+fn resolve_inside(dst: &Path, p: &Path) -> Option<PathBuf> {
+    let mut todo = p
+        .components()
+        .map(|c| c.as_os_str().to_owned())
+        .collect::<VecDeque<_>>();
+    let mut resolved = PathBuf::new();
+    let mut hops = 0;
+    while let Some(c) = todo.pop_front() {
+        match Path::new(&c).components().next()? {
+            Normal(n) => resolved.push(n),
+            CurDir => continue,
+            ParentDir if resolved.pop() => continue,
+            _ => return None,
+        }
+        let Ok(target) = dst.join(&resolved).read_link() else {
+            continue;
+        };
+        hops += 1;
+        if hops > 40 || target.is_absolute() {
+            return None;
+        }
+        resolved.pop();
+        for c in target.components().rev() {
+            todo.push_front(c.as_os_str().to_owned());
+        }
+    }
+    Some(resolved)
+}
+
+/// Unpacks a tarball. Returns the number of unpacked entries.
+/// Entries that are absolute, contain `..`, or are links to (or through links to) outside `dst` are rejected.
+fn unpack_tarchive(
+    archive: &mut TArch,
+    dst: &Path,
+    toplevel: Option<&Path>,
+    root: Option<&Path>,
+    filter: &Filter,
+) -> io::Result<usize> {
     if dst.symlink_metadata().is_err() {
         create_dir_all(dst)?;
     }
@@ -150,108 +535,184 @@ fn unpack_tarchive(archive: &mut TArch, dst: &Path) -> io::Result<()> {
     // descendants), to ensure that directory permissions do not interfer with descendant
     // extraction.
     let mut directories = Vec::new();
+    let mut links = Vec::new();
+    let mut unpacked = 0;
     for entry in archive.entries()? {
-        let entry = entry?;
-        let mut entry = (dst.join(skip_toplevel(&entry.path()?)), entry);
-        if entry.1.header().entry_type() == Directory {
-            directories.push(entry);
-        } else {
-            create_dir_all(entry.0.parent().unwrap())?;
-            entry.1.unpack(entry.0)?;
+        let mut entry = entry?;
+        if entry.header().entry_type().is_pax_global_extensions() {
+            continue;
         }
-    }
-    for mut dir in directories {
-        dir.1.unpack(dir.0)?;
-    }
-    Ok(())
-}
-
-fn get_zfile(zarchive: &mut ZArch, search: &str, out: &mut String) -> ZipResult<()> {
-    for i in 0..zarchive.len() {
-        let mut file = zarchive.by_index(i)?;
-        if let Some(n) = file.enclosed_name() {
-            if let Some(base) = &Path::new(n).file_name() {
-                if base.to_string_lossy() == search {
-                    file.read_to_string(out)?;
-                    return Ok(());
-                }
+        let raw = entry.path()?.to_path_buf();
+        check_path(&raw)?;
+        let archive_path = skip_toplevel(&raw, toplevel);
+        let Some(rel) = select(&archive_path, root) else {
+            continue;
+        };
+        if entry.header().entry_type() != Directory && !filter.includes(&archive_path, &rel) {
+            continue;
+        }
+        let path = dst.join(&rel);
+        check_inside(&path, dst)?;
+        unpacked += 1;
+        match entry.header().entry_type() {
+            Directory => {
+                directories.push((path, entry));
+                continue;
+            }
+            Symlink => {
+                let target = entry.link_name()?.ok_or_else(|| escape(&raw))?;
+                resolve_link(rel.parent().unwrap_or(&rel), &target).ok_or_else(|| escape(&raw))?;
+                links.push(path.clone());
             }
+            Link => {
+                // hard links point to a earlier entry of the archive, which may have been skipped (then so is the link)
+                let target = entry.link_name()?.ok_or_else(|| escape(&raw))?;
+                check_path(&target)?;
+                let Some(target) = select(&skip_toplevel(&target, toplevel), root)
+                    .map(|t| dst.join(t))
+                    .filter(|t| t.symlink_metadata().is_ok())
+                else {
+                    unpacked -= 1;
+                    continue;
+                };
+                check_inside(&target, dst)?;
+                create_dir_all(path.parent().unwrap())?;
+                hard_link(target, path)?;
+                continue;
+            }
+            _ => {}
         }
+        create_dir_all(path.parent().unwrap())?;
+        entry.unpack(path)?;
+    }
+    for (path, mut dir) in directories {
+        check_inside(&path, dst)?;
+        dir.unpack(path)?;
     }
-    Err(ZipError::FileNotFound)
+    // links may be chained to escape (`a -> b/..`, `b -> ..`), so check where they actually point
+    for link in links {
+        let rel = link.strip_prefix(dst).map_err(|_| escape(&link))?;
+        resolve_inside(dst, rel).ok_or_else(|| escape(&link))?;
+    }
+    Ok(unpacked)
 }
 
-fn get_gfile(tarchive: &mut TArch, file: &str, out: &mut String) -> io::Result<()> {
-    for entry in tarchive.entries()? {
-        let mut entry = entry?;
-        if let Ok(p) = entry.path() {
-            if p.file_name().ok_or(io::ErrorKind::InvalidData)? == file {
-                entry.read_to_string(out)?;
-                return Ok(());
-            }
+impl ArchiveType {
+    fn tar(&mut self) -> Option<&mut TArch> {
+        match self {
+            ArchiveType::Gzip(t)
+            | ArchiveType::Xz(t)
+            | ArchiveType::Bzip2(t)
+            | ArchiveType::Zstd(t)
+            | ArchiveType::Tar(t) => Some(t),
+            ArchiveType::Zip(_) => None,
         }
     }
-    Err(io::ErrorKind::InvalidData.into())
 }
 
 impl Archive {
-    pub fn unpack(&mut self, dst: &Path) -> Result<()> {
-        match &mut self.inner {
-            ArchiveType::Gzip(g) => unpack_tarchive(g, dst)?,
-            ArchiveType::Zip(z) => unpack_zarchive(z, dst)?,
+    /// Opens a archive, listing its entries (and reading its [LISTED_FILES]).
+    pub fn new(source: CompressionType) -> Result<Self> {
+        let uri = source.data().map_or(String::new(), |d| d.uri.clone());
+        let listed_file = |p: &Path| {
+            p.file_name()
+                .is_some_and(|n| LISTED_FILES.iter().any(|f| n == *f))
+        };
+        let (mut raw, mut listed) = (vec![], vec![]);
+        match open(source.clone())? {
+            ArchiveType::Zip(mut z) => {
+                for i in 0..z.len() {
+                    let mut file = z.by_index(i)?;
+                    let Some(p) = file.enclosed_name().map(Path::to_path_buf) else {
+                        continue;
+                    };
+                    if file.is_file() && listed_file(&p) {
+                        let mut contents = String::new();
+                        file.read_to_string(&mut contents)?;
+                        listed.push((p.clone(), contents));
+                    }
+                    raw.push(p);
+                }
+            }
+            mut t => {
+                for entry in t.tar().unwrap().entries()? {
+                    let mut entry = entry?;
+                    // `git archive` puts the commit in a global header
+                    if entry.header().entry_type().is_pax_global_extensions() {
+                        continue;
+                    }
+                    let p = entry.path()?.to_path_buf();
+                    if entry.header().entry_type().is_file() && listed_file(&p) {
+                        let mut contents = String::new();
+                        entry.read_to_string(&mut contents)?;
+                        listed.push((p.clone(), contents));
+                    }
+                    raw.push(p);
+                }
+            }
         }
-        Ok(())
+        Ok(Self {
+            toplevel: shared_toplevel(&raw),
+            source,
+            uri,
+            raw,
+            listed,
+        })
     }
 
-    pub fn get_file(&mut self, file: &str, out: &mut String) -> Result<()> {
-        match &mut self.inner {
-            ArchiveType::Gzip(g) => get_gfile(g, file, out)?,
-            ArchiveType::Zip(z) => get_zfile(z, file, out)?,
+    /// Unpacks this archive to `dst`, skipping the toplevel directory (if every entry is in one).
+    /// If `root` is given, only that subdirectory is unpacked (to `dst`).
+    /// Files that the [Filter] does not include are skipped.
+    pub fn unpack(&self, dst: &Path, root: Option<&Path>, filter: &Filter) -> Result<()> {
+        let top = self.toplevel.as_deref();
+        let unpacked = match open(self.source.clone())? {
+            ArchiveType::Zip(mut z) => unpack_zarchive(&mut z, dst, top, root, filter)?,
+            mut t => unpack_tarchive(t.tar().unwrap(), dst, top, root, filter)?,
+        };
+        if let (Some(root), 0) = (root, unpacked) {
+            return Err(anyhow!("{} has no {}", self.uri, root.display()));
         }
         Ok(())
     }
 
-    fn wrap(wrap: ArchiveType, uri: String) -> Self {
-        Self { inner: wrap, uri }
+    /// Lists the files in this archive, with the toplevel skipped.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.raw
+            .iter()
+            .map(|p| skip_toplevel(p, self.toplevel.as_deref()))
+            .collect()
     }
 
-    pub fn new(value: CompressionType) -> Result<Self> {
-        match value {
-            CompressionType::Gzip(data) => Ok(Self::new_gzip(data.bytes, data.uri)),
-            CompressionType::Zip(data) => Self::new_zip(data.bytes, data.uri),
-            _ => unreachable!(),
-        }
+    /// The contents of `name` (one of the [LISTED_FILES]) at the top of the archive, with the toplevel skipped.
+    pub fn file(&self, name: &str) -> Option<&str> {
+        self.listed
+            .iter()
+            .find(|(p, _)| skip_toplevel(p, self.toplevel.as_deref()) == Path::new(name))
+            .map(|(_, contents)| contents.as_str())
     }
 
-    pub fn new_gzip(value: Vec<u8>, uri: String) -> Self {
-        Self::wrap(
-            ArchiveType::Gzip(Box::new(Tarchive::new(GzDecoder::new(Cursor::new(value))))),
-            uri,
-        )
+    /// The [CompressionType] of this archive, without the bytes.
+    pub fn compression_type(&self) -> CompressionType {
+        let d = Data::new_uri(self.uri.clone());
+        match self.source {
+            CompressionType::Gzip(_) => CompressionType::Gzip(d),
+            CompressionType::Xz(_) => CompressionType::Xz(d),
+            CompressionType::Bzip2(_) => CompressionType::Bzip2(d),
+            CompressionType::Zstd(_) => CompressionType::Zstd(d),
+            CompressionType::Tar(_) => CompressionType::Tar(d),
+            _ => CompressionType::Zip(d),
+        }
     }
 
-    pub fn new_zip(value: Vec<u8>, uri: String) -> Result<Self> {
-        Ok(Self::wrap(
-            ArchiveType::Zip(Zarchive::new(Cursor::new(value))?),
-            uri,
-        ))
-    }
     /// async trait + lifetimes = boom
-    pub async fn into_package(mut self, client: Client) -> Result<Package> {
-        let mut contents = String::new();
-        {
-            ctx!(
-                self.get_file("package.json", &mut contents),
-                "searching for package.json"
-            )?;
-        }
-        let ty = match self.inner {
-            ArchiveType::Zip(_) => CompressionType::Zip(Data::new_uri(self.uri)),
-            ArchiveType::Gzip(_) => CompressionType::Gzip(Data::new_uri(self.uri)),
-        };
+    pub async fn into_package(self, client: Client) -> Result<Package> {
+        let contents = self
+            .file("package.json")
+            .ok_or_else(|| anyhow!("searching for package.json: {} has none", self.uri))?;
+        let ty = self.compression_type();
         ctx!(
             ctx!(
-                ConfigFile::parse(&contents, ConfigType::JSON, client).await,
+                ConfigFile::parse(contents, ConfigType::JSON, client).await,
                 "parsing config file from package.json inside zipfile"
             )?
             .into_package(ty),
@@ -259,3 +720,204 @@ impl Archive {
         )
     }
 }
+
+/// Opens `source` for reading, from the start.
+fn open(source: CompressionType) -> Result<ArchiveType> {
+    fn tar(r: impl Read + Send + 'static) -> Box<TArch> {
+        Box::new(Tarchive::new(Box::new(r)))
+    }
+    Ok(match source {
+        CompressionType::Gzip(d) => ArchiveType::Gzip(tar(GzDecoder::new(d.reader()?))),
+        CompressionType::Zip(d) => ArchiveType::Zip(Zarchive::new(d.reader()?)?),
+        CompressionType::Xz(d) => ArchiveType::Xz(tar(XzDecoder::new(d.reader()?))),
+        CompressionType::Bzip2(d) => ArchiveType::Bzip2(tar(BzDecoder::new(d.reader()?))),
+        CompressionType::Zstd(d) => ArchiveType::Zstd(tar(zstd::Decoder::new(d.reader()?)?)),
+        CompressionType::Tar(d) => ArchiveType::Tar(tar(d.reader()?)),
+        _ => unreachable!(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::archive::*;
+    use std::fs::read_to_string;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn malicious() {
+        let t = crate::test_utils::mktemp().await;
+        let c = t.2;
+        let dst = t.0.path().join("project/addons/evil");
+        for tarball in [
+            "traversal.tgz",
+            "absolute.tgz",
+            "symlink.tgz",
+            "chained-symlink.tgz",
+            "hardlink.tgz",
+            "traversal.zip",
+        ] {
+            let bytes = crate::get!(c, "{}/evil/-/{tarball}", c.registry)
+                .unwrap()
+                .bytes()
+                .await
+                .unwrap()
+                .to_vec();
+            let res = Archive::new(CompressionType::from(bytes, tarball.into()).unwrap())
+                .unwrap()
+                .unpack(&dst, None, &Filter::default());
+            assert!(res.is_err(), "{tarball} should not unpack");
+            for escaped in [
+                "escaped.txt",
+                "project/escaped.txt",
+                "project/addons/escaped.txt",
+            ] {
+                assert!(
+                    !t.0.path().join(escaped).exists(),
+                    "{tarball} escaped to {escaped}"
+                );
+            }
+            assert!(!Path::new("/tmp/gpm-escaped.txt").exists());
+            std::fs::remove_dir_all(&dst).unwrap();
+        }
+
+        // a dangling link, that only leaves through a other link (`x/y/b -> ../..`)
+        let mut tar = tar::Builder::new(vec![]);
+        for (path, target) in [
+            ("package/x/y/b", "../.."),
+            ("package/a", "x/y/b/../escaped.txt"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_size(0);
+            tar.append_link(&mut header, path, target).unwrap();
+        }
+        let bytes = tar.into_inner().unwrap();
+        let res = Archive::new(CompressionType::from(bytes, "dangling.tar".into()).unwrap())
+            .unwrap()
+            .unpack(&dst, None, &Filter::default());
+        assert!(res.is_err(), "dangling.tar should not unpack");
+        std::fs::remove_dir_all(&dst).unwrap();
+
+        // links inside of the package are fine
+        let bytes = crate::get!(c, "{}/evil/-/links.tgz", c.registry)
+            .unwrap()
+            .bytes()
+            .await
+            .unwrap()
+            .to_vec();
+        Archive::new(CompressionType::from(bytes, "links.tgz".into()).unwrap())
+            .unwrap()
+            .unpack(&dst, None, &Filter::default())
+            .unwrap();
+        for f in ["alias.gd", "hard.gd"] {
+            assert_eq!(read_to_string(dst.join(f)).unwrap(), "extends Node\n");
+        }
+    }
+
+    #[test]
+    fn filter() {
+        let paths = ["a.gd", "docs/.gdignore", "docs/x.gd", "test/t.gd"].map(PathBuf::from);
+        let f = Filter::new(
+            &paths,
+            None,
+            Some("# tests\ntest/\n!test/keep.txt\n*.gd\n!a.gd\n"),
+            &["*.md".into()],
+        )
+        .unwrap();
+        let includes = |p: &str| f.includes(Path::new(p), Path::new(p));
+        assert!(includes("a.gd"));
+        assert!(!includes("b.gd"));
+        assert!(!includes("docs/x.gd"));
+        assert!(!includes("test/t.gd"));
+        assert!(includes("test/keep.txt"));
+        assert!(!includes("sub/README.md"));
+
+        // npmignore is ignored when there is a files list
+        let f = Filter::new(&paths, Some(r#"{"files": ["test"]}"#), Some("test/"), &[]).unwrap();
+        let includes = |p: &str| f.includes(Path::new(p), Path::new(p));
+        assert!(!includes("a.gd"));
+        assert!(includes("test/t.gd"));
+        assert!(includes("package.json"));
+    }
+
+    #[test]
+    fn filtered_hardlink() {
+        let mut tar = tar::Builder::new(vec![]);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(13);
+        header.set_mode(0o644);
+        tar.append_data(&mut header, "package/big.gd", "extends Node\n".as_bytes())
+            .unwrap();
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(tar::EntryType::Link);
+        header.set_size(0);
+        tar.append_link(&mut header, "package/alias.gd", "package/big.gd")
+            .unwrap();
+        let c = CompressionType::from(tar.into_inner().unwrap(), "x.tar".into()).unwrap();
+        let dir = TempDir::new().unwrap();
+        let archive = Archive::new(c).unwrap();
+        let filter = Filter::new(&archive.paths(), None, None, &["big.gd".into()]).unwrap();
+        archive.unpack(dir.path(), None, &filter).unwrap();
+        assert!(!dir.path().join("big.gd").exists());
+        assert!(!dir.path().join("alias.gd").exists());
+    }
+
+    #[test]
+    fn formats() {
+        let mut tar = tar::Builder::new(vec![]);
+        for (path, contents) in [
+            ("package/package.json", r#"{"name":"x","version":"1.0.0"}"#),
+            ("package/main.gd", "extends Node\n"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(&mut header, path, contents.as_bytes())
+                .unwrap();
+        }
+        let tar = tar.into_inner().unwrap();
+        let compress = |mut w: Box<dyn Write>| {
+            w.write_all(&tar).unwrap();
+        };
+        let mut gz = vec![];
+        compress(Box::new(flate2::write::GzEncoder::new(
+            &mut gz,
+            Default::default(),
+        )));
+        let mut xz = vec![];
+        compress(Box::new(xz2::write::XzEncoder::new(&mut xz, 6)));
+        let mut bz = vec![];
+        compress(Box::new(bzip2::write::BzEncoder::new(
+            &mut bz,
+            Default::default(),
+        )));
+        let zst = zstd::encode_all(Cursor::new(&tar), 0).unwrap();
+
+        for (bytes, ty) in [
+            (gz, "Gzip"),
+            (xz, "Xz"),
+            (bz, "Bzip2"),
+            (zst, "Zstd"),
+            (tar.clone(), "Tar"),
+        ] {
+            // the uri lies, it should be ignored
+            let c = CompressionType::from(bytes, "x.zip".into()).unwrap();
+            assert!(format!("{c:?}").starts_with(ty), "{c:?} should be {ty}");
+            let archive = Archive::new(c).unwrap();
+            assert!(archive
+                .file("package.json")
+                .unwrap()
+                .contains(r#""name":"x""#));
+            let dir = TempDir::new().unwrap();
+            archive
+                .unpack(dir.path(), None, &Filter::default())
+                .unwrap();
+            assert_eq!(
+                read_to_string(dir.path().join("main.gd")).unwrap(),
+                "extends Node\n"
+            );
+        }
+        assert!(CompressionType::from(b"<html>".to_vec(), "x.tgz".into()).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{ctx, get, Client};
+
+use anyhow::{anyhow, Context, Result};
+use semver_rs::Version;
+use serde::Deserialize;
+
+/// An asset from the Godot Asset Library (https://godotengine.org/asset-library).
+/// Depended upon with `assetlib:<asset_id>` or `assetlib:<title>`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Asset {
+    pub title: String,
+    pub version_string: String,
+    pub download_url: String,
+    /// The sha256 of the download, if the asset has one.
+    #[serde(default)]
+    pub download_hash: String,
+}
+
+#[derive(Deserialize)]
+struct Search {
+    result: Vec<SearchResult>,
+}
+
+#[derive(Deserialize)]
+struct SearchResult {
+    asset_id: String,
+    title: String,
+}
+
+impl Asset {
+    /// Is this package name a asset library dependency?
+    pub fn is_asset(name: &str) -> bool {
+        name.starts_with("assetlib:")
+    }
+
+    /// Gets an asset by its id, or by its title.
+    pub async fn get(spec: &str, client: &Client) -> Result<Asset> {
+        let id = spec
+            .strip_prefix("assetlib:")
+            .ok_or_else(|| anyhow!("{spec} is not a asset library dependency"))?;
+        let id = if id.chars().all(|c| c.is_ascii_digit()) {
+            id.to_string()
+        } else {
+            Self::search(id, client).await?
+        };
+        let resp = ctx!(
+            get!(client, "{}/asset/{id}", client.assetlib)?.text().await,
+            "getting asset {id} from {}",
+            client.assetlib
+        )?;
+        ctx!(
+            serde_json::from_str::<Asset>(&resp),
+            "parsing asset {id} from {}",
+            client.assetlib
+        )
+    }
+
+    /// Finds the id of the asset with this title (case insensitive).
+    async fn search(title: &str, client: &Client) -> Result<String> {
+        let resp = ctx!(
+            client
+                .get(format!("{}/asset", client.assetlib))
+                .query(&[("filter", title)])
+                .send()
+                .await?
+                .text()
+                .await,
+            "searching for asset {title}"
+        )?;
+        let search = ctx!(
+            serde_json::from_str::<Search>(&resp),
+            "parsing search results for {title}"
+        )?;
+        search
+            .result
+            .into_iter()
+            .find(|r| r.title.eq_ignore_ascii_case(title))
+            .map(|r| r.asset_id)
+            .ok_or_else(|| anyhow!("No asset named {title} found"))
+    }
+
+    /// The version of this asset.
+    /// Asset library versions are freeform (`1.1`, `v2`), so they get coerced into semver.
+    pub fn version(&self) -> Result<Version> {
+        let mut parts = self
+            .version_string
+            .trim()
+            .trim_start_matches('v')
+            .split('.')
+            .map(|p| {
+                p.chars()
+                    .take_while(char::is_ascii_digit)
+                    .collect::<String>()
+            })
+            .take_while(|p| !p.is_empty())
+            .collect::<Vec<_>>();
+        parts.resize(3, "0".into());
+        ctx!(
+            Version::new(&parts.join(".")).parse(),
+            "parsing version {} of asset {}",
+            self.version_string,
+            self.title
+        )
+    }
+
+    /// A directory name for this asset, for when the archive has no `addons/<name>` to name it after.
+    pub fn slug(&self) -> String {
+        self.title
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assetlib::*;
+
+    #[test]
+    fn version() {
+        let asset = |v: &str| Asset {
+            title: "Cool Thing (Godot 4)".into(),
+            version_string: v.into(),
+            download_url: String::new(),
+            download_hash: String::new(),
+        };
+        for (v, want) in [("1.1", "1.1.0"), ("v2", "2.0.0"), ("3.0.1-beta", "3.0.1")] {
+            assert_eq!(asset(v).version().unwrap().to_string(), want);
+        }
+        assert_eq!(asset("1").slug(), "cool-thing-godot-4");
+    }
+}
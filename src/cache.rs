@@ -33,11 +33,11 @@ impl Cache {
     }
 
     /// Deadlocks when mutable reference held
-    pub fn get(&self, name: &str) -> O<VersionsCache> {
+    pub fn get(&self, name: &str) -> O<'_, VersionsCache> {
         self.inner.get(name)
     }
     /// Deadlocks when reference held
-    pub fn get_mut(&self, name: &str) -> Option<RefMut<String, VersionsCache>> {
+    pub fn get_mut(&self, name: &str) -> Option<RefMut<'_, String, VersionsCache>> {
         self.inner.get_mut(name)
     }
     /// Deadlocks when reference held
@@ -59,12 +59,12 @@ impl VersionsCache {
     }
     pub fn iter_versions(
         &mut self,
-    ) -> impl Iterator<Item = (Version, RefMutMulti<String, CacheEntry>)> {
+    ) -> impl Iterator<Item = (Version, RefMutMulti<'_, String, CacheEntry>)> {
         self.iter_mut()
             .map(|x| (Self::version_of(x.key(), x.value().clone()), x))
     }
 
-    pub fn get(&self, v: &str) -> Option<Ref<String, CacheEntry>> {
+    pub fn get(&self, v: &str) -> Option<Ref<'_, String, CacheEntry>> {
         self.inner.get(v)
     }
 
@@ -81,11 +81,11 @@ impl VersionsCache {
         }
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = RefMulti<String, CacheEntry>> {
+    pub fn iter(&self) -> impl Iterator<Item = RefMulti<'_, String, CacheEntry>> {
         self.inner.iter()
     }
 
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = R> {
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = R<'_>> {
         self.inner.iter_mut()
     }
 
@@ -94,11 +94,18 @@ impl VersionsCache {
     }
 
     #[must_use]
-    /// if found and unparsed, swaps unparsed for parsed
-    pub fn find_version(&mut self, v: &Range) -> Option<R> {
+    /// Finds the newest version matching `v` that supports one of the `engine` versions,
+    /// falling back to the newest matching version if none do (check [CacheEntry::engine] afterwards).
+    pub fn find_version(&mut self, v: &Range, engine: &[Version]) -> Option<R<'_>> {
+        let compatible = self.iter().any(|e| {
+            v.test(&Self::version_of(e.key(), e.value().clone()))
+                && crate::project::compatible(e.value().engine(), engine)
+        });
         let mut newest = None;
         for (version, entry) in self.iter_versions() {
-            if v.test(&version) {
+            if v.test(&version)
+                && (!compatible || crate::project::compatible(entry.value().engine(), engine))
+            {
                 // if v.exact() { return immediately }
                 if let Some((_, v)) = &newest {
                     if version.cmp(v) == std::cmp::Ordering::Less {
@@ -182,6 +189,15 @@ impl CacheEntry {
         Ok(())
     }
 
+    /// The godot versions this entry supports, if known.
+    pub fn engine(&self) -> Option<&str> {
+        match self {
+            CacheEntry::Parsed(p) => p.manifest.engine.as_deref(),
+            CacheEntry::Manifest(m) => m.godot.engine.as_deref(),
+            _ => None,
+        }
+    }
+
     pub fn get_package(&self) -> Package {
         match self {
             CacheEntry::Parsed(p) => p.clone(),
@@ -196,3 +212,31 @@ impl CacheEntry {
     //     }
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::*;
+
+    #[test]
+    fn engine() {
+        let mut cache = VersionsCache::default();
+        for (version, engine) in [("1.0.0", "<4"), ("1.1.0", ">=4.1"), ("2.0.0", ">=4.3")] {
+            let m: ParsedManifest = serde_json::from_str(&format!(
+                r#"{{"dist": {{"shasum": "", "tarball": ""}}, "version": "{version}", "godot": {{"engine": "{engine}"}}}}"#
+            ))
+            .unwrap();
+            cache.insert(version.into(), m.into());
+        }
+        let v = |s: &str| Version::new(s).parse().unwrap();
+        let mut find = |range: &str, engine: &[Version]| {
+            cache
+                .find_version(&Range::new(range).parse().unwrap(), engine)
+                .map(|e| e.key().clone())
+        };
+        assert_eq!(find("*", &[]).as_deref(), Some("2.0.0"));
+        assert_eq!(find("*", &[v("4.2.0")]).as_deref(), Some("1.1.0"));
+        assert_eq!(find("*", &[v("3.5.0")]).as_deref(), Some("1.0.0"));
+        // nothing compatible, so the newest
+        assert_eq!(find("^2", &[v("3.5.0")]).as_deref(), Some("2.0.0"));
+    }
+}
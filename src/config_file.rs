@@ -1,10 +1,13 @@
+use crate::archive::CompressionType;
 use crate::conversions::*;
 use crate::ctx;
+use crate::package::layout::Layout;
+use crate::package::parsing::{GodotSection, ParsedDependency};
 use crate::package::Manifest;
 use crate::package::Package;
 use crate::Client;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use console::style;
 use semver_rs::Version;
 use serde::{Deserialize, Serialize};
@@ -17,6 +20,8 @@ pub struct ConfigFile {
     name: String,
     version: String,
     pub packages: Vec<Package>,
+    pub layout: Layout,
+    engine: Option<String>,
     // hooks: there are no hooks now
 }
 
@@ -28,14 +33,19 @@ pub struct ConfigFile {
 struct ParsedConfig {
     // support NPM package.json files (also allows gpm -c package.json -u)
     #[serde(alias = "dependencies")]
-    packages: HashMap<String, String>,
+    packages: HashMap<String, ParsedDependency>,
     #[serde(default)]
     name: String,
     #[serde(default)]
     version: String,
+    #[serde(default, skip_serializing_if = "GodotSection::is_empty")]
+    godot: GodotSection,
+    #[serde(default, skip_serializing_if = "Layout::is_default")]
+    layout: Layout,
 }
 
 #[derive(Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum ConfigType {
     JSON,
     YAML,
@@ -54,10 +64,18 @@ impl From<&ConfigFile> for ParsedConfig {
             packages: from
                 .packages
                 .iter()
-                .map(|p| (p.name.to_string(), p.manifest.version.to_string()))
+                .map(|p| {
+                    let version = match &p.manifest.tarball {
+                        CompressionType::Local(d) => d.uri.clone(),
+                        _ => p.manifest.version.to_string(),
+                    };
+                    (p.name.to_string(), ParsedDependency::Version(version))
+                })
                 .collect(),
             name: String::new(),
             version: String::new(),
+            godot: GodotSection::default(),
+            layout: from.layout.clone(),
         }
     }
 }
@@ -70,14 +88,19 @@ impl TryFromAsync<ParsedConfig> for ConfigFile {
             "turning ParsedConfig into ConfigFile"
         )
         .unwrap();
-        for mut p in &mut packages {
+        for p in &mut packages {
             p.indirect = false
         }
-        Ok(ConfigFile {
+        let mut cfg = ConfigFile {
             packages,
             name: value.name,
             version: value.version,
-        })
+            engine: value.godot.engine,
+            layout: value.layout,
+        };
+        let layout = cfg.layout.clone();
+        cfg.for_each(|p| p.layout = layout.clone());
+        Ok(cfg)
     }
 }
 
@@ -147,17 +170,31 @@ impl ConfigFile {
         cfg
     }
 
+    /// Reads just the [Layout] of a config file, without resolving any packages.
+    /// Errors if the file cant be parsed as hjson, yaml or toml.
+    pub fn layout_of(contents: &str) -> Result<Layout> {
+        let mut errors = vec![];
+        for t in [ConfigType::JSON, ConfigType::YAML, ConfigType::TOML] {
+            match ParsedConfig::parse(contents, t) {
+                Ok(c) => return Ok(c.layout),
+                Err(e) => errors.push(format!("{t:?}: {e}")),
+            }
+        }
+        Err(anyhow!("Parsing CFG failed ({})", errors.join(", ")))
+    }
+
     pub async fn parse(txt: &str, t: ConfigType, client: Client) -> Result<ConfigFile> {
         ParsedConfig::parse(txt, t)?.try_into_async(client).await
     }
 
-    pub fn into_package(self, uri: crate::archive::CompressionType) -> Result<Package> {
+    pub fn into_package(self, uri: CompressionType) -> Result<Package> {
         Ok(Package::from_manifest(
             Manifest {
                 version: Version::new(&self.version).parse()?,
                 shasum: None,
                 tarball: uri,
                 dependencies: self.packages,
+                engine: self.engine,
             },
             self.name,
         ))
@@ -166,9 +203,18 @@ impl ConfigFile {
     /// Creates a lockfile for this config file.
     /// note: Lockfiles are currently unused.
     pub fn lock(&mut self, cwd: &Path) -> String {
+        self.lock_with(|p| p.is_installed(cwd))
+    }
+
+    /// The lockfile once every package is installed, see [ConfigFile::lock].
+    pub fn planned_lock(&mut self) -> String {
+        self.lock_with(|_| true)
+    }
+
+    fn lock_with(&mut self, include: impl Fn(&Package) -> bool) -> String {
         let mut pkgs = vec![];
         for mut p in self.collect() {
-            if p.is_installed(cwd) {
+            if include(&p) {
                 p.prepare_lock();
                 pkgs.push(p);
             };
@@ -256,4 +302,35 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn layout() {
+        let t = crate::test_utils::mktemp().await;
+        let (c, cwd) = (t.2, t.0.path());
+        let mut cfg = ConfigFile::new(
+            &r#"{"packages": {"@bendn/test": "2.0.10"}, "layout": {"root": "lib", "flatten": true}}"#
+                .into(),
+            c.clone(),
+        )
+        .await;
+        crate::update(
+            &mut cfg,
+            crate::UpdateOptions::default(),
+            crate::Verbosity::Quiet,
+            c,
+            cwd,
+        )
+        .await;
+        assert!(cwd.join("lib/bendn_test/main.gd").exists());
+        let deps = cwd.join("lib/__gpm_deps/bendn_gdcli/1.2.5");
+        assert!(deps.exists());
+        let main = std::fs::read_to_string(cwd.join("lib/bendn_test/main.gd")).unwrap();
+        assert!(main.contains("res://lib/__gpm_deps/bendn_gdcli/1.2.5/"));
+        let lock = cfg.lock(cwd);
+        assert_eq!(crate::verify::verify(&mut cfg, Some(&lock), cwd), []);
+
+        let layout = ConfigFile::layout_of("layout:\n  root: lib\n").unwrap();
+        assert_eq!(layout.root, Path::new("lib"));
+        assert!(ConfigFile::layout_of(r#"{"layout": {"flatten": "yes"}}"#).is_err());
+    }
 }
@@ -0,0 +1,58 @@
+use crate::{ctx, Client};
+
+use anyhow::{Context, Result};
+use sha1::{Digest, Sha1};
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// A archive streamed to a temporary file (deleted on drop).
+pub struct Download {
+    pub file: NamedTempFile,
+    /// The hex encoded sha1 of the file.
+    pub sha1: String,
+}
+
+/// Streams `uri` into a temporary file, hashing it on the way, so whole archives never sit in memory.
+/// `progress` is called with the number of bytes downloaded so far, and the total (if the server sent it).
+pub async fn download(
+    client: &Client,
+    uri: &str,
+    mut progress: impl FnMut(u64, Option<u64>),
+) -> Result<Download> {
+    let mut resp = ctx!(client.get(uri).send().await, "downloading {uri}")?;
+    let total = resp.content_length();
+    let mut file = NamedTempFile::new()?;
+    let mut hasher = Sha1::new();
+    let mut done = 0;
+    while let Some(chunk) = ctx!(resp.chunk().await, "downloading {uri}")? {
+        hasher.update(&chunk);
+        ctx!(file.write_all(&chunk), "writing {uri} to disk")?;
+        done += chunk.len() as u64;
+        progress(done, total);
+    }
+    file.flush()?;
+    Ok(Download {
+        file,
+        sha1: format!("{:x}", hasher.finalize()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::download::*;
+
+    #[tokio::test]
+    async fn download() {
+        let t = crate::test_utils::mktemp().await;
+        let c = t.2;
+        let uri = format!("{}/@bendn/test/-/test-2.0.10.tgz", c.registry);
+        let mut calls = vec![];
+        let d = super::download(&c, &uri, |done, total| calls.push((done, total)))
+            .await
+            .unwrap();
+        let len = d.file.as_file().metadata().unwrap().len();
+        assert_eq!(calls.last(), Some(&(len, Some(len))));
+        let bytes = crate::get!(c, "{uri}").unwrap().bytes().await.unwrap();
+        assert_eq!(d.sha1, format!("{:x}", Sha1::digest(&bytes)));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::config_file::ConfigFile;
+use crate::package::{copy_dir, Package};
+use crate::transaction::Transaction;
+use crate::{patch, project, putils, verify, Client};
+
+use indicatif::HumanBytes;
+use std::collections::HashSet;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Prints what `gpm update` would do, without writing anything to the project.
+/// Packages still get downloaded (to a temporary directory), to show how their loads would be changed.
+pub async fn update(cfg: &mut ConfigFile, client: Client, cwd: &Path) {
+    let scratch = Transaction::scratch().expect("Should be able to create a temporary directory");
+    let mut packages = cfg.collect().into_iter().collect::<Vec<_>>();
+    packages.sort();
+    let mut staged_packages = HashSet::new();
+    for mut p in packages {
+        if p.is_linked(cwd) {
+            println!("{:>12} {p} (linked)", putils::green("Skipping"));
+            continue;
+        }
+        if p.is_up_to_date(cwd) {
+            println!("{:>12} {p} (up to date)", putils::green("Skipping"));
+            continue;
+        }
+        let mut size = 0;
+        let rel = p
+            .stage(client.clone(), &scratch, |done, _| size = done)
+            .await;
+        let staged = scratch.staged(&rel);
+        let size = match size {
+            0 => String::new(), // local packages are copied
+            n => format!(" ({})", HumanBytes(n)),
+        };
+        println!(
+            "{:>12} {p} from {}{size}",
+            putils::green("Would download"),
+            p.manifest.tarball,
+        );
+        let verb = match p.is_installed(cwd) {
+            true => "Would replace",
+            false => "Would install",
+        };
+        println!("{:>12} {}", putils::green(verb), rel.display());
+        print_modifications(&mut p, &scratch, &staged, cwd);
+        staged_packages.insert(p.to_string());
+    }
+    let orphans = verify::orphaned(cfg, cwd);
+    for dir in &orphans {
+        let rel = dir.strip_prefix(cwd).unwrap_or(dir).display();
+        println!("{:>12} {rel}", putils::green("Would prune"));
+    }
+    if let Some(mut project) = project::Project::read(cwd) {
+        let dirs = orphans
+            .iter()
+            .map(|d| project::res_path(d.strip_prefix(cwd).unwrap_or(d)))
+            .collect::<Vec<_>>();
+        print_unregister(&mut project, &dirs);
+        let new = scratch.staged(Path::new(""));
+        let (plugins, autoloads) =
+            crate::registrations(cfg, |p| match staged_packages.contains(&p.to_string()) {
+                true => new.clone(),
+                false => cwd.to_path_buf(),
+            });
+        for plugin in project.enable_plugins(&plugins) {
+            println!("{:>12} {plugin}", putils::green("Would enable"));
+        }
+        let (added, clashes) = project.add_autoloads(&autoloads, cwd);
+        for name in added {
+            println!("{:>12} autoload {name}", putils::green("Would register"));
+        }
+        for (name, path) in clashes {
+            eprintln!(
+                "{:>12} autoload {name} already exists ({path}), would not register it",
+                putils::warn()
+            );
+        }
+    }
+    println!("{:>12} the lockfile:", putils::green("Would write"));
+    println!("{}", cfg.planned_lock());
+}
+
+/// Prints the diff of the load changes [Package::modify] would make to the package staged at `staged`.
+fn print_modifications(p: &mut Package, scratch: &Transaction, staged: &Path, cwd: &Path) {
+    let clean = TempDir::new().expect("Should be able to create a temporary directory");
+    copy_dir(staged, clean.path()).expect("Should be able to copy staged package");
+    p.modify_staged(scratch, cwd);
+    let diff = patch::diff(clean.path(), staged).expect("Should be able to diff staged package");
+    if !diff.is_empty() {
+        println!("{:>12} {p}:", putils::green("Would modify"));
+        print!("{diff}");
+    }
+}
+
+/// Prints what `gpm purge` would do, without deleting anything.
+pub fn purge(cfg: &mut ConfigFile, cwd: &Path) {
+    let mut packages = cfg
+        .collect()
+        .into_iter()
+        .filter(|p| p.is_installed(cwd))
+        .collect::<Vec<_>>();
+    packages.sort();
+    for p in &packages {
+        let what = if p.is_linked(cwd) { " (link)" } else { "" };
+        println!(
+            "{:>12} {}{what}",
+            putils::green("Would delete"),
+            p.download_dir(Path::new("")).display()
+        );
+    }
+    if let Some(mut project) = project::Project::read(cwd) {
+        let dirs = packages
+            .iter()
+            .map(|p| project::res_path(&p.download_dir(Path::new(""))))
+            .collect::<Vec<_>>();
+        print_unregister(&mut project, &dirs);
+    }
+    println!("{:>12} the lockfile:", putils::green("Would write"));
+    println!("[]");
+}
+
+/// Prints the editor plugins and autoloads in `dirs` (`res://` paths) that would be removed from `project`.
+fn print_unregister(project: &mut project::Project, dirs: &[String]) {
+    for plugin in project.disable_plugins_in(dirs) {
+        println!("{:>12} {plugin}", putils::green("Would disable"));
+    }
+    for name in project.remove_autoloads_in(dirs) {
+        println!("{:>12} autoload {name}", putils::green("Would remove"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dry_run::*;
+    use std::fs::{create_dir_all, read_dir, read_to_string, write};
+
+    #[tokio::test]
+    async fn nothing_written() {
+        let t = crate::test_utils::mktemp().await;
+        let (c, cwd) = (t.2, t.0.path());
+        let mut cfg =
+            ConfigFile::new(&r#"packages: {"@bendn/test": "2.0.10"}"#.into(), c.clone()).await;
+        let files = || {
+            read_dir(cwd)
+                .unwrap()
+                .chain(read_dir(cwd.join("addons")).unwrap())
+                .count()
+        };
+        let before = files();
+        update(&mut cfg, c.clone(), cwd).await;
+        assert_eq!(files(), before);
+
+        crate::update(
+            &mut cfg,
+            crate::UpdateOptions::default(),
+            crate::Verbosity::Quiet,
+            c,
+            cwd,
+        )
+        .await;
+        let installed = crate::test_utils::hashd(&cwd.join("addons"));
+        purge(&mut cfg, cwd);
+        assert_eq!(crate::test_utils::hashd(&cwd.join("addons")), installed);
+    }
+
+    #[tokio::test]
+    async fn project_untouched() {
+        let t = crate::test_utils::mktemp().await;
+        let (c, cwd) = (t.2, t.0.path());
+        let src = cwd.join("my-addon");
+        create_dir_all(&src).unwrap();
+        write(
+            src.join("package.json"),
+            r#"{"name":"my-addon","version":"0.1.0","godot":{"autoloads":{"Mine":"mine.gd"}}}"#,
+        )
+        .unwrap();
+        write(src.join("mine.gd"), "extends Node\n").unwrap();
+        let project = "config_version=5\n";
+        write(cwd.join("project.godot"), project).unwrap();
+        let mut cfg = ConfigFile::new(
+            &format!(r#"packages: {{"my-addon": "file:{}"}}"#, src.display()),
+            c.clone(),
+        )
+        .await;
+        // not installed, so the autoload (Mine) is read from the staged copy
+        assert_eq!(cfg.packages[0].autoloads(cwd), []);
+        update(&mut cfg, c, cwd).await;
+        assert_eq!(read_to_string(cwd.join("project.godot")).unwrap(), project);
+        assert!(!cwd.join("addons/my-addon").exists());
+    }
+}
@@ -0,0 +1,224 @@
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Ident,
+    /// A string literal. `escaped` if it contains escape sequences (so its source is not its value).
+    Str {
+        escaped: bool,
+    },
+    Number,
+    Punct(char),
+}
+
+/// A token, with the byte range of its source.
+/// For strings, `inner` is the range between the quotes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: Kind,
+    pub span: Range<usize>,
+    pub inner: Range<usize>,
+}
+
+/// A small GDScript lexer, just enough to find the paths given to `load` and `preload`.
+/// Splits GDScript source into tokens, skipping whitespace and comments.
+/// Does not validate anything, garbage in garbage out.
+pub fn lex(src: &str) -> Vec<Token> {
+    let b = src.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < b.len() {
+        let c = b[i];
+        let start = i;
+        let tok = |kind, end: usize| Token {
+            kind,
+            span: start..end,
+            inner: start..end,
+        };
+        match c {
+            b'#' => {
+                while i < b.len() && b[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            _ if c.is_ascii_whitespace() || c == b'\\' => i += 1, // \ continues lines
+            b'"' | b'\'' => {
+                let (t, end) = string(b, i, start, false);
+                tokens.push(t);
+                i = end;
+            }
+            // r"raw", &"StringName", ^"NodePath"
+            b'r' | b'&' | b'^' if matches!(b.get(i + 1), Some(b'"' | b'\'')) => {
+                let (t, end) = string(b, i + 1, start, c == b'r');
+                tokens.push(t);
+                i = end;
+            }
+            _ if c == b'_' || c.is_ascii_alphabetic() || c >= 0x80 => {
+                while i < b.len() && (b[i] == b'_' || b[i].is_ascii_alphanumeric() || b[i] >= 0x80)
+                {
+                    i += 1;
+                }
+                tokens.push(tok(Kind::Ident, i));
+            }
+            _ if c.is_ascii_digit() => {
+                while i < b.len() && (b[i] == b'_' || b[i] == b'.' || b[i].is_ascii_alphanumeric())
+                {
+                    i += 1;
+                }
+                tokens.push(tok(Kind::Number, i));
+            }
+            _ => {
+                i += 1;
+                tokens.push(tok(Kind::Punct(c as char), i));
+            }
+        }
+    }
+    tokens
+}
+
+/// Lexes the string whose (first) quote is at `q`. Returns the token and where it ends.
+fn string(b: &[u8], q: usize, start: usize, raw: bool) -> (Token, usize) {
+    let quote = b[q];
+    let triple = b.get(q..q + 3) == Some(&[quote; 3]);
+    let qlen = if triple { 3 } else { 1 };
+    let mut i = q + qlen;
+    let mut escaped = false;
+    let inner_start = i;
+    let inner_end = loop {
+        if i >= b.len() {
+            break b.len(); // unterminated
+        }
+        if b[i] == b'\\' && !raw {
+            escaped = true;
+            i += 2;
+            continue;
+        }
+        if b[i] == quote && (!triple || b.get(i..i + 3) == Some(&[quote; 3])) {
+            break i;
+        }
+        if b[i] == b'\n' && !triple {
+            break i; // unterminated
+        }
+        i += 1;
+    };
+    let end = (inner_end + qlen).min(b.len());
+    (
+        Token {
+            kind: Kind::Str { escaped },
+            span: start..end,
+            inner: inner_start..inner_end,
+        },
+        end,
+    )
+}
+
+/// Finds the string literals that are the first argument of `load`, `preload` or `ResourceLoader.load`.
+/// Returns the ranges between the quotes, so they can be replaced while keeping the quoting.
+pub fn load_paths(src: &str) -> Vec<Range<usize>> {
+    let tokens = lex(src);
+    let text = |t: &Token| &src[t.span.clone()];
+    let mut paths = vec![];
+    for (i, t) in tokens.iter().enumerate() {
+        if t.kind != Kind::Ident || !matches!(text(t), "load" | "preload") {
+            continue;
+        }
+        // foo.load() is someone elses load, unless foo is ResourceLoader
+        if i > 0 && tokens[i - 1].kind == Kind::Punct('.') {
+            let is_loader = i > 1
+                && tokens[i - 2].kind == Kind::Ident
+                && text(&tokens[i - 2]) == "ResourceLoader"
+                && text(t) == "load";
+            if !is_loader {
+                continue;
+            }
+        }
+        if let [open, arg, ..] = &tokens[i + 1..] {
+            // StringNames and NodePaths (&"", ^"") are not paths
+            let plain = !matches!(src.as_bytes()[arg.span.start], b'&' | b'^');
+            if open.kind == Kind::Punct('(') && arg.kind == (Kind::Str { escaped: false }) && plain
+            {
+                paths.push(arg.inner.clone());
+            }
+        }
+    }
+    paths
+}
+
+/// The global class name of a script (`class_name Foo`), if it has one.
+pub fn class_name(src: &str) -> Option<&str> {
+    let tokens = lex(src);
+    tokens.windows(2).find_map(|w| {
+        let [kw, name] = w else { unreachable!() };
+        (kw.kind == Kind::Ident
+            && &src[kw.span.clone()] == "class_name"
+            && name.kind == Kind::Ident)
+            .then(|| &src[name.span.clone()])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gdscript::*;
+
+    #[test]
+    fn load_paths() {
+        let src = r#"extends Node
+# const A = preload("res://commented.gd")
+const B = preload('res://b.gd')
+const C = """
+load("res://in_string.gd")
+"""
+var d = load("res://d_" + suffix)
+var e = ResourceLoader.load("res://e (1).gd", "Script")
+var f = config.load("user://f.cfg")
+var g = preload(&"not_a_path")
+var h = load("res://h\"quoted\".gd")
+var i = preload(r"res://i.gd")
+"#;
+        let found = super::load_paths(src)
+            .into_iter()
+            .map(|r| &src[r])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            ["res://b.gd", "res://d_", "res://e (1).gd", "res://i.gd"]
+        );
+    }
+
+    #[test]
+    fn lex() {
+        let kinds = super::lex("var x = 1.5 # hi\nx += &\"y\"")
+            .into_iter()
+            .map(|t| t.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                Kind::Ident,
+                Kind::Ident,
+                Kind::Punct('='),
+                Kind::Number,
+                Kind::Ident,
+                Kind::Punct('+'),
+                Kind::Punct('='),
+                Kind::Str { escaped: false },
+            ]
+        );
+    }
+
+    #[test]
+    fn class_name() {
+        assert_eq!(
+            super::class_name("@tool\nextends Node\nclass_name Console # the console\n"),
+            Some("Console")
+        );
+        assert_eq!(
+            super::class_name("class_name Icon, \"res://icon.png\"\nextends Node"),
+            Some("Icon")
+        );
+        assert_eq!(
+            super::class_name("# class_name Nope\nvar s = \"class_name X\""),
+            None
+        );
+    }
+}
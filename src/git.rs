@@ -0,0 +1,208 @@
+use crate::ctx;
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::path::Path;
+use tempfile::{NamedTempFile, TempDir};
+use tokio::process::Command;
+
+/// A git repository dependency.
+/// Supports `git+<url>#<ref>` and `github:<owner>/<repo>#<ref>`,
+/// where the ref can be a branch, a tag, or a commit. If no ref is given, the default branch is used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GitRepo {
+    pub url: String,
+    pub reference: Option<String>,
+}
+
+impl GitRepo {
+    /// Is this package name a git dependency?
+    pub fn is_git(name: &str) -> bool {
+        name.starts_with("git+") || name.starts_with("github:")
+    }
+
+    pub fn parse(spec: &str) -> Result<Self> {
+        let (repo, reference) = match spec.split_once('#') {
+            Some((r, re)) if !re.is_empty() => (r, Some(re.to_string())),
+            Some((r, _)) => (r, None),
+            None => (spec, None),
+        };
+        let url = if let Some(url) = repo.strip_prefix("git+") {
+            url.to_string()
+        } else if let Some(shorthand) = repo.strip_prefix("github:") {
+            if shorthand.split('/').count() != 2 {
+                bail!("github shorthand should look like github:owner/repo, got {spec}")
+            }
+            format!("https://github.com/{shorthand}.git")
+        } else {
+            bail!("{spec} is not a git dependency")
+        };
+        Ok(Self { url, reference })
+    }
+
+    /// The spec pinned to a commit, used for the lockfile and for reinstalling.
+    pub fn pin(&self, commit: &str) -> String {
+        format!("git+{}#{commit}", self.url)
+    }
+
+    /// Clones the repository, resolves the ref, and creates a tar.gz of the resolved commit.
+    /// Returns the commit hash and the archive (a temporary file).
+    pub async fn archive(&self) -> Result<(String, NamedTempFile)> {
+        let tmp = TempDir::new()?;
+        ctx!(
+            git(
+                &["clone", "--quiet", "--no-checkout", &self.url, "repo"],
+                tmp.path()
+            )
+            .await,
+            "cloning {}",
+            self.url
+        )?;
+        let repo = tmp.path().join("repo");
+        let commit = self.resolve(&repo).await?;
+        let file = NamedTempFile::new()?;
+        let out = file.path().to_string_lossy();
+        ctx!(
+            git_raw(
+                &[
+                    "archive",
+                    "--format=tar.gz",
+                    "--prefix=package/",
+                    "-o",
+                    &out,
+                    &commit
+                ],
+                &repo
+            )
+            .await,
+            "archiving {commit} of {}",
+            self.url
+        )?;
+        Ok((commit, file))
+    }
+
+    /// Resolves the ref to a commit hash.
+    /// Branches other than the default only exist as `origin/<branch>` after cloning, so try that too.
+    async fn resolve(&self, repo: &Path) -> Result<String> {
+        let reference = self.reference.as_deref().unwrap_or("HEAD");
+        for candidate in [reference.to_string(), format!("origin/{reference}")] {
+            let spec = format!("{candidate}^{{commit}}");
+            if let Ok(commit) = git(&["rev-parse", "--verify", "--quiet", &spec], repo).await {
+                return Ok(commit);
+            }
+        }
+        Err(anyhow!("could not find ref {reference} in {}", self.url))
+    }
+}
+
+async fn git_raw(args: &[&str], dir: &Path) -> Result<Vec<u8>> {
+    let out = ctx!(
+        Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .await,
+        "running git (is it installed?)"
+    )?;
+    if !out.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+    Ok(out.stdout)
+}
+
+async fn git(args: &[&str], dir: &Path) -> Result<String> {
+    Ok(String::from_utf8(git_raw(args, dir).await?)?
+        .trim()
+        .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::git::*;
+    use crate::package::Package;
+    use std::fs::{create_dir, read_to_string, write};
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            GitRepo::parse("github:bend-n/splitter#main").unwrap(),
+            GitRepo {
+                url: "https://github.com/bend-n/splitter.git".into(),
+                reference: Some("main".into())
+            }
+        );
+        assert_eq!(
+            GitRepo::parse("git+https://example.com/x.git").unwrap(),
+            GitRepo {
+                url: "https://example.com/x.git".into(),
+                reference: None
+            }
+        );
+        assert!(GitRepo::parse("github:nope").is_err());
+    }
+
+    async fn commit(src: &Path, msg: &str) {
+        git(&["add", "-A"], src).await.unwrap();
+        git(
+            &[
+                "-c",
+                "user.name=gpm",
+                "-c",
+                "user.email=gpm@localhost",
+                "commit",
+                "--quiet",
+                "-m",
+                msg,
+            ],
+            src,
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn git_dependency() {
+        let t = crate::test_utils::mktemp().await;
+        let src = t.0.path().join("src");
+        create_dir(&src).unwrap();
+        git(&["init", "--quiet"], &src).await.unwrap();
+        write(
+            src.join("package.json"),
+            r#"{"name":"@bendn/gitdep","version":"1.0.0"}"#,
+        )
+        .unwrap();
+        write(src.join("main.gd"), "extends Node\n").unwrap();
+        commit(&src, "1.0.0").await;
+        git(&["tag", "v1.0.0"], &src).await.unwrap();
+        let tagged = git(&["rev-parse", "HEAD"], &src).await.unwrap();
+        write(src.join("main.gd"), "extends Node2D\n").unwrap();
+        commit(&src, "unreleased").await;
+        git(
+            &["clone", "--quiet", "--bare", "src", "bare.git"],
+            t.0.path(),
+        )
+        .await
+        .unwrap();
+
+        let spec = format!(
+            "git+file://{}#v1.0.0",
+            t.0.path().join("bare.git").display()
+        );
+        let mut p = Package::new(spec, "1.x".into(), t.2.clone()).await.unwrap();
+        assert_eq!(p.to_string(), "@bendn/gitdep@1.0.0");
+        p.download(t.2.clone(), t.0.path()).await;
+        assert_eq!(
+            read_to_string(p.download_dir(t.0.path()).join("main.gd")).unwrap(),
+            "extends Node\n"
+        );
+        p.prepare_lock();
+        assert!(p
+            .manifest
+            .tarball
+            .to_string()
+            .ends_with(&format!("#{tagged}")));
+    }
+}
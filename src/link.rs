@@ -0,0 +1,136 @@
+use crate::ctx;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::env::var_os;
+use std::fs::{create_dir_all, read_link, read_to_string};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The global directory where `gpm link` registers packages.
+/// `$GPM_HOME/links`, defaulting to `~/.gpm/links`.
+pub fn links_dir() -> PathBuf {
+    var_os("GPM_HOME")
+        .map(PathBuf::from)
+        .or_else(|| var_os("HOME").map(|h| Path::new(&h).join(".gpm")))
+        .or_else(|| var_os("USERPROFILE").map(|h| Path::new(&h).join(".gpm")))
+        .expect("Should be able to find home directory (set $GPM_HOME)")
+        .join("links")
+}
+
+/// Registers the package in `dir` globally, by symlinking it into `links`.
+/// Returns the name of the package (from its package.json).
+pub fn register(dir: &Path, links: &Path) -> Result<String> {
+    #[derive(Deserialize)]
+    struct Named {
+        name: String,
+    }
+    let contents = ctx!(
+        read_to_string(dir.join("package.json")),
+        "reading package.json of {}",
+        dir.display()
+    )?;
+    let name = ctx!(
+        deser_hjson::from_str::<Named>(&contents),
+        "reading name from package.json"
+    )?
+    .name;
+    let entry = links.join(&name);
+    replace_with_link(&dir.canonicalize()?, &entry)?;
+    Ok(name)
+}
+
+/// Replaces `dst` (where `name` is installed, like `addons/<name>`) with a symlink to the package registered as `name`.
+/// Returns the linked directory.
+pub fn link(name: &str, links: &Path, dst: &Path) -> Result<PathBuf> {
+    let target = read_link(links.join(name))
+        .map_err(|_| anyhow!("{name} is not registered (run `gpm link` in its directory first)"))?;
+    if !target.is_dir() {
+        return Err(anyhow!(
+            "{name} is registered to {}, which no longer exists",
+            target.display()
+        ));
+    }
+    replace_with_link(&target, dst)?;
+    Ok(target)
+}
+
+/// Replaces `dst` with a link to `src`, removing whatever was there before.
+/// Existing links are removed without touching what they point to.
+fn replace_with_link(src: &Path, dst: &Path) -> Result<()> {
+    if is_link(dst) {
+        unlink(dst)?;
+    } else if dst.is_dir() {
+        std::fs::remove_dir_all(dst)?;
+    }
+    create_dir_all(dst.parent().unwrap())?;
+    ctx!(
+        symlink_dir(src, dst),
+        "linking {} to {}",
+        dst.display(),
+        src.display()
+    )
+}
+
+/// Is this path a symlink (rather than a directory)?
+pub fn is_link(p: &Path) -> bool {
+    p.symlink_metadata()
+        .map(|m| m.file_type().is_symlink())
+        .unwrap_or(false)
+}
+
+/// Removes a symlink, never what it points to.
+pub fn unlink(p: &Path) -> io::Result<()> {
+    #[cfg(windows)]
+    return std::fs::remove_dir(p); // directory symlinks are directories, on windows
+    #[cfg(not(windows))]
+    std::fs::remove_file(p)
+}
+
+#[cfg(unix)]
+pub fn symlink_dir(src: &Path, dst: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(src, dst)
+}
+
+#[cfg(windows)]
+pub fn symlink_dir(src: &Path, dst: &Path) -> io::Result<()> {
+    std::os::windows::fs::symlink_dir(src, dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::link::*;
+    use crate::package::Package;
+    use std::fs::{create_dir_all, write};
+    use tempfile::TempDir;
+
+    #[test]
+    fn link_and_purge() {
+        let t = TempDir::new().unwrap();
+        let (addon, links, game) = (
+            t.path().join("addon"),
+            t.path().join("links"),
+            t.path().join("game"),
+        );
+        create_dir_all(&addon).unwrap();
+        create_dir_all(game.join("addons/@bendn/test")).unwrap();
+        write(addon.join("package.json"), r#"{"name":"@bendn/test"}"#).unwrap();
+        write(addon.join("main.gd"), "extends Node\n").unwrap();
+
+        let installed = game.join("addons/@bendn/test");
+        assert!(link("@bendn/test", &links, &installed).is_err());
+        assert_eq!(register(&addon, &links).unwrap(), "@bendn/test");
+        link("@bendn/test", &links, &installed).unwrap();
+        assert!(is_link(&installed));
+        assert!(installed.join("main.gd").exists());
+
+        let p = Package {
+            name: "@bendn/test".into(),
+            ..Default::default()
+        };
+        assert!(p.is_linked(&game));
+        p.purge(&game);
+        assert!(!installed.exists());
+        assert!(addon.join("main.gd").exists());
+    }
+}
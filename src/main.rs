@@ -1,27 +1,39 @@
 mod archive;
+mod assetlib;
 mod cache;
 mod config_file;
 mod conversions;
+mod download;
+mod dry_run;
+mod gdscript;
+mod git;
+mod link;
 mod package;
+mod patch;
+mod project;
+mod resource;
 mod theme;
+mod transaction;
 mod verbosity;
+mod verify;
 
 use cache::Cache;
 use config_file::{ConfigFile, ConfigType};
 use conversions::*;
 use package::parsing::ParsedPackage;
-use package::Package;
+use package::{meta, Package};
+use transaction::Transaction;
 
 use anyhow::Result;
 use async_recursion::async_recursion;
 use clap::{ColorChoice, Parser, Subcommand, ValueEnum};
 use console::{self, Term};
 use futures::stream::{self, StreamExt};
-use indicatif::{HumanCount, HumanDuration, ProgressBar, ProgressIterator};
+use indicatif::{HumanBytes, HumanCount, HumanDuration, ProgressBar, ProgressIterator};
 use lazy_static::lazy_static;
 use reqwest::{Client as RealClient, IntoUrl, RequestBuilder};
 use std::collections::HashSet;
-use std::fs::{create_dir, read_dir, read_to_string, remove_dir, write};
+use std::fs::{copy, create_dir_all, read_dir, read_to_string, remove_dir, remove_dir_all, write};
 use std::io::{stdin, Read};
 use std::path::{Path, PathBuf};
 use std::sync::mpsc::channel;
@@ -72,16 +84,66 @@ struct Args {
     )]
     /// Registry to use.
     registry: String,
+    #[arg(
+        default_value = "https://godotengine.org/asset-library/api",
+        global = true,
+        long = "assetlib"
+    )]
+    /// Asset library to use, for assetlib: packages.
+    assetlib: String,
+    #[arg(long = "project-dir", global = true)]
+    /// The project to work on. Defaults to the nearest directory (from the current one up) with the config file or a project.godot.
+    /// Relative config and lock file paths are relative to it.
+    project_dir: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
 enum Actions {
     #[clap(short_flag = 'u')]
     /// Downloads the latest versions of your wanted packages.
-    Update,
+    Update {
+        #[arg(long = "link", default_value = "false")]
+        /// Symlink local (file:) packages instead of copying them.
+        link: bool,
+        #[arg(long = "force", default_value = "false")]
+        /// Overwrite files in addons/ that were edited since installing, without asking.
+        force: bool,
+        #[arg(long = "dry-run", default_value = "false")]
+        /// Only print what would be downloaded, modified and removed.
+        dry_run: bool,
+    },
+    /// Edit a dependency, and keep the edits as a patch (in patches/).
+    /// Reinstalls the package for editing, run again with --commit when done.
+    Patch {
+        /// The package to patch, as `name@version` if several versions of it are installed.
+        name: String,
+        #[arg(long = "commit", default_value = "false")]
+        /// Save the edits made to addons/<package> as its patch.
+        commit: bool,
+        #[arg(long = "force", default_value = "false")]
+        /// Reinstall over edits that are not in the patch yet, without asking.
+        force: bool,
+    },
+    /// Checks the installed packages against the lockfile, and for local edits.
+    /// Exits with 1 if anything is wrong.
+    Verify,
+    /// Deletes old versions in addons/__gpm_deps that no package depends on anymore,
+    /// and packages that were removed from the config file. Update does this too.
+    Prune {
+        #[arg(long = "dry-run", default_value = "false")]
+        /// Only list what would be deleted.
+        dry_run: bool,
+        #[arg(long = "force", default_value = "false")]
+        /// Delete files that were edited since installing, without asking.
+        force: bool,
+    },
     #[clap(short_flag = 'p')]
     /// Deletes all installed packages.
-    Purge,
+    Purge {
+        #[arg(long = "dry-run", default_value = "false")]
+        /// Only print what would be deleted.
+        dry_run: bool,
+    },
     /// Prints a tree of all the wanted packages, and their dependencies.
     #[command(long_about = "
 Print a tree of all the wanted packages, and their dependencies.
@@ -107,9 +169,20 @@ Produces output like
         #[arg(long = "packages", num_args = 0..)]
         packages: Vec<ParsedPackage>,
     },
+    /// Symlinks a local addon into this project, for development.
+    #[command(long_about = "
+Symlink a local addon into this project, for development.
+Run `gpm link` inside the addon to register it,
+then `gpm link <name>` inside a project to replace addons/<name> with a symlink to it.
+`gpm purge` removes the symlink, leaving the addon untouched.")]
+    Link {
+        /// The registered package to link. If not specified, registers the current directory.
+        name: Option<String>,
+    },
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+#[allow(clippy::upper_case_acronyms)]
 /// Charset for the tree subcommand.
 enum CharSet {
     /// Unicode characters (├── └──).
@@ -134,17 +207,32 @@ pub struct Client {
     real: RealClient,
     cache: Cache,
     registry: String,
+    assetlib: String,
+    /// The godot versions of the project, see [project::Project::engine_versions].
+    engine: Vec<semver_rs::Version>,
 }
 
 impl Client {
-    pub fn wrap(real: RealClient, cache: Cache, registry: String) -> Self {
+    pub fn wrap(real: RealClient, cache: Cache, registry: String, assetlib: String) -> Self {
         Self {
             real,
             registry,
+            assetlib,
             cache,
+            engine: vec![],
         }
     }
 
+    /// Only resolve packages that support one of the `engine` versions (if possible).
+    pub fn with_engine(mut self, engine: Vec<semver_rs::Version>) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    pub fn engine(&self) -> &[semver_rs::Version] {
+        &self.engine
+    }
+
     pub fn get<U: IntoUrl>(&self, url: U) -> RequestBuilder {
         self.real.get(url)
     }
@@ -190,10 +278,28 @@ async fn main() {
         ColorChoice::Never => set_colors(false),
         ColorChoice::Auto => set_colors(Term::stdout().is_term() && Term::stderr().is_term()),
     }
-    let client = mkclient(args.registry);
-    let mut cfg = {
+    // `gpm link` (registering) works on the addon in the current directory, not a project,
+    // and `gpm init` makes a new project in the current directory (even if its inside another one)
+    let here = matches!(
+        args.action,
+        Actions::Link { name: None } | Actions::Init { .. }
+    );
+    let cwd = match args.project_dir {
+        Some(dir) => dir,
+        None if here => current_dir().expect("Should be able to read cwd"),
+        None => {
+            let cwd = current_dir().expect("Should be able to read cwd");
+            find_root(&cwd, &args.config_file).unwrap_or(cwd)
+        }
+    };
+    std::env::set_current_dir(&cwd)
+        .unwrap_or_else(|e| panic!("Cant enter project dir {}: {e}", cwd.display()));
+    let cwd = current_dir().expect("Should be able to read cwd");
+    let client = mkclient(args.registry, args.assetlib)
+        .with_engine(project::Project::read(&cwd).map_or(vec![], |p| p.engine_versions()));
+    async fn read_cfg(path: &Path, client: Client) -> ConfigFile {
         let mut contents = String::from("");
-        if args.config_file == Path::new("-") {
+        if path == Path::new("-") {
             let bytes = stdin()
                 .read_to_string(&mut contents)
                 .expect("Stdin read should be ok");
@@ -201,10 +307,10 @@ async fn main() {
                 panic!("Stdin should not be empty");
             };
         } else {
-            contents = read_to_string(args.config_file).expect("Reading config file should be ok");
+            contents = read_to_string(path).expect("Reading config file should be ok");
         };
-        ConfigFile::new(&contents, client.clone()).await
-    };
+        ConfigFile::new(&contents, client).await
+    }
     fn lock(cfg: &mut ConfigFile, path: PathBuf, cwd: &Path) {
         let lockfile = cfg.lock(cwd);
         if path == Path::new("-") {
@@ -214,13 +320,77 @@ async fn main() {
         }
     }
     let _ = BEGIN.elapsed(); // needed to initialize the instant for whatever reason
-    let cwd = current_dir().expect("Should be able to read cwd");
     match args.action {
-        Actions::Update => {
-            update(&mut cfg, true, args.verbosity, client.clone(), &cwd).await;
+        Actions::Update { dry_run: true, .. } => {
+            let mut cfg = read_cfg(&args.config_file, client.clone()).await;
+            dry_run::update(&mut cfg, client, &cwd).await;
+        }
+        Actions::Update { link, force, .. } => {
+            let mut cfg = read_cfg(&args.config_file, client.clone()).await;
+            update(
+                &mut cfg,
+                UpdateOptions {
+                    link,
+                    force,
+                    ..Default::default()
+                },
+                args.verbosity,
+                client.clone(),
+                &cwd,
+            )
+            .await;
             lock(&mut cfg, args.lock_file, &cwd);
         }
-        Actions::Purge => {
+        Actions::Patch {
+            name,
+            commit,
+            force,
+        } => {
+            let mut cfg = read_cfg(&args.config_file, client.clone()).await;
+            let mut p = find_package(&mut cfg, &name).unwrap_or_else(|e| panic!("{e}"));
+            let name = p.name.clone();
+            if commit {
+                match patch::commit(&mut p, client, &cwd).await {
+                    Ok(Some(file)) => {
+                        println!("{:>12} {}", putils::green("Saved"), file.display())
+                    }
+                    Ok(None) => println!("{:>12} {p} has no edits", putils::green("Removed")),
+                    Err(e) => panic!("Saving patch failed: {e:#}"),
+                }
+            } else {
+                let dir = patch::edit(&mut p, client, force, &cwd)
+                    .await
+                    .expect("Installing package for editing should work");
+                println!(
+                    "{:>12} edit {}, then run `gpm patch --commit {name}`",
+                    putils::green("Ready"),
+                    dir.display()
+                );
+            }
+        }
+        Actions::Verify => {
+            let mut cfg = read_cfg(&args.config_file, client.clone()).await;
+            let lock = read_to_string(&args.lock_file).ok();
+            let problems = verify::verify(&mut cfg, lock.as_deref(), &cwd);
+            if problems.is_empty() {
+                println!("{:>12} everything is installed", putils::green("Verified"));
+                return;
+            }
+            for p in &problems {
+                eprintln!("{:>12} {p}", putils::err());
+            }
+            std::process::exit(1);
+        }
+        Actions::Prune { dry_run, force } => {
+            let mut cfg = read_cfg(&args.config_file, client.clone()).await;
+            prune(&mut cfg, dry_run, force, args.verbosity, &cwd);
+        }
+        Actions::Purge { dry_run: true } => {
+            let mut cfg = read_cfg(&args.config_file, client.clone()).await;
+            dry_run::purge(&mut cfg, &cwd);
+        }
+        Actions::Purge { .. } => {
+            let mut cfg = read_cfg(&args.config_file, client.clone()).await;
             purge(&mut cfg, args.verbosity, &cwd);
             lock(&mut cfg, args.lock_file, &cwd);
         }
@@ -228,17 +398,20 @@ async fn main() {
             charset,
             prefix,
             print_tarballs,
-        } => println!(
-            "{}",
-            tree(
-                &mut cfg, // no locking needed
-                charset,
-                prefix,
-                print_tarballs,
-                client
+        } => {
+            let mut cfg = read_cfg(&args.config_file, client.clone()).await;
+            println!(
+                "{}",
+                tree(
+                    &mut cfg, // no locking needed
+                    charset,
+                    prefix,
+                    print_tarballs,
+                    client
+                )
+                .await
             )
-            .await
-        ),
+        }
         Actions::Init { packages } => {
             init(
                 packages
@@ -251,10 +424,54 @@ async fn main() {
             .await
             .expect("Initializing cfg should be ok");
         }
+        Actions::Link { name } => {
+            let links = link::links_dir();
+            match name {
+                Some(name) => {
+                    // linking works without a config file, but follows its layout if there is one
+                    let layout = match read_to_string(&args.config_file) {
+                        Ok(c) => ConfigFile::layout_of(&c).unwrap_or_else(|e| panic!("{e:#}")),
+                        Err(_) => Default::default(),
+                    };
+                    let dst = layout.direct(Path::new(""), &name);
+                    let target = link::link(&name, &links, &cwd.join(&dst))
+                        .expect("Linking package should work");
+                    if args.verbosity.info() {
+                        println!(
+                            "{:>12} {} -> {}",
+                            putils::green("Linked"),
+                            dst.display(),
+                            target.display()
+                        );
+                    }
+                }
+                None => {
+                    let name = link::register(&cwd, &links).expect("Registering link should work");
+                    if args.verbosity.info() {
+                        println!(
+                            "{:>12} {name} (use `gpm link {name}` in a project to use it)",
+                            putils::green("Registered")
+                        );
+                    }
+                }
+            }
+        }
     }
 }
 
-pub fn mkclient(r: String) -> Client {
+/// Finds the project root: the nearest directory (from `start` up) with the config file `cfg` or a project.godot,
+/// like cargo finds its Cargo.toml.
+fn find_root(start: &Path, cfg: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|d| {
+            (cfg != Path::new("-") && d.join(cfg).is_file())
+                || d.join(project::PROJECT_FILE).is_file()
+        })
+        .map(Path::to_path_buf)
+}
+
+pub fn mkclient(r: String, assetlib: String) -> Client {
     let mut headers = reqwest::header::HeaderMap::new();
     headers.insert(
         "User-Agent",
@@ -272,12 +489,45 @@ pub fn mkclient(r: String) -> Client {
             .unwrap(),
         Cache::new(),
         r,
+        assetlib,
     )
 }
 
-async fn update(cfg: &mut ConfigFile, modify: bool, v: Verbosity, client: Client, cwd: &Path) {
-    if !cwd.join("addons").exists() {
-        create_dir(cwd.join("addons")).expect("Should be able to create addons folder");
+/// How [update] installs the packages.
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateOptions {
+    /// Rewrite the loads of the packages, see [Package::modify_staged].
+    pub modify: bool,
+    /// Link local packages instead of copying them.
+    pub link: bool,
+    /// Overwrite files that were edited after installing, without asking.
+    pub force: bool,
+}
+
+impl Default for UpdateOptions {
+    fn default() -> Self {
+        Self {
+            modify: true,
+            link: false,
+            force: false,
+        }
+    }
+}
+
+async fn update(
+    cfg: &mut ConfigFile,
+    UpdateOptions {
+        modify,
+        link,
+        force,
+    }: UpdateOptions,
+    v: Verbosity,
+    client: Client,
+    cwd: &Path,
+) {
+    let root = cwd.join(&cfg.layout.root);
+    if !root.exists() {
+        create_dir_all(root).expect("Should be able to create addons folder");
     }
     let packages = cfg.collect();
     if v.debug() {
@@ -312,29 +562,75 @@ async fn update(cfg: &mut ConfigFile, modify: bool, v: Verbosity, client: Client
     };
     enum Status {
         Processing(String),
-        Finished(String),
+        /// Bytes downloaded (and the total, if known).
+        Progress(String, u64, Option<u64>),
+        /// Finished, and wether it was downloaded.
+        Finished(String, bool),
+    }
+    enum Outcome {
+        /// Staged, with the files that were edited since the last install.
+        Staged(PathBuf, Vec<PathBuf>),
+        /// To link once everything else is in place, with the files that were edited in its copy.
+        Link(Box<Package>, Vec<PathBuf>),
+        UpToDate,
+        Skipped,
     }
     let bar_or_info = v.bar() || v.info();
     let (tx, rx) = bar_or_info.then(channel).unzip();
+    // nothing in addons/ is touched until every package is staged
+    let staging = Transaction::new(cwd).expect("Should be able to create staging dir");
+    let transaction = &staging;
     let buf = stream::iter(packages)
         .map(|mut p| {
             let p_name = p.to_string();
             let tx = if bar_or_info { tx.clone() } else { None };
             let client = client.clone();
             async move {
+                let mut outcome = Outcome::Skipped;
                 if bar_or_info {
                     tx.as_ref()
                         .unwrap()
                         .send(Status::Processing(p_name.clone()))
                         .unwrap();
                 }
-                p.download(client, cwd).await;
-                if modify {
-                    p.modify(cwd);
-                };
+                if p.is_linked(cwd) && !(link && p.is_local()) {
+                    if v.info() {
+                        println!("{:>12} {p_name} (linked)", putils::green("Skipping"));
+                    }
+                } else if link && p.is_local() {
+                    // modifying would modify the source
+                    let modified = if force { vec![] } else { p.modified_files(cwd) };
+                    outcome = Outcome::Link(Box::new(p), modified);
+                } else if p.is_up_to_date(cwd) {
+                    if v.info() {
+                        println!("{:>12} {p_name} (up to date)", putils::green("Skipping"));
+                    }
+                    outcome = Outcome::UpToDate;
+                } else {
+                    let rel = p
+                        .stage(client, transaction, |done, total| {
+                            if let Some(tx) = &tx {
+                                tx.send(Status::Progress(p_name.clone(), done, total))
+                                    .unwrap();
+                            }
+                        })
+                        .await;
+                    if modify {
+                        p.modify_staged(transaction, cwd);
+                    };
+                    patch::apply_for(&p, &transaction.staged(&rel), cwd)
+                        .expect("Patch should apply");
+                    p.mark_staged(transaction, cwd);
+                    let modified = if force { vec![] } else { p.modified_files(cwd) };
+                    outcome = Outcome::Staged(rel, modified);
+                }
                 if bar_or_info {
-                    tx.unwrap().send(Status::Finished(p_name.clone())).unwrap();
+                    let downloaded = matches!(outcome, Outcome::Staged(..));
+                    tx.unwrap()
+                        .send(Status::Finished(p_name.clone(), downloaded))
+                        .unwrap();
                 }
+                outcome
             }
         })
         .buffer_unordered(PARALLEL);
@@ -342,42 +638,253 @@ async fn update(cfg: &mut ConfigFile, modify: bool, v: Verbosity, client: Client
     // for mut p in packages { p.download(client.clone()).await; if modify { p.modify().unwrap(); }; bar.inc(1); }
     let handler = if bar_or_info {
         Some(thread::spawn(move || {
-            let mut running = vec![];
+            let mut running: Vec<(String, Option<String>)> = vec![];
             let rx = rx.unwrap();
             while let Ok(status) = rx.recv() {
                 match status {
                     Status::Processing(p) => {
-                        running.push(p);
+                        running.push((p, None));
                     }
-                    Status::Finished(p) => {
-                        running.swap_remove(running.iter().position(|e| e == &p).unwrap());
-                        if v.info() {
+                    Status::Progress(p, done, total) => {
+                        if let Some((_, progress)) = running.iter_mut().find(|(e, _)| e == &p) {
+                            *progress = Some(match total {
+                                Some(total) => {
+                                    format!("{}/{}", HumanBytes(done), HumanBytes(total))
+                                }
+                                None => HumanBytes(done).to_string(),
+                            });
+                        }
+                    }
+                    Status::Finished(p, downloaded) => {
+                        running.swap_remove(running.iter().position(|(e, _)| e == &p).unwrap());
+                        if v.info() && downloaded {
                             bar.suspend(|| println!("{:>12} {p}", putils::green("Downloaded")));
                         }
                         bar.inc(1);
                     }
                 }
-                bar.set_message(running.join(", "));
+                bar.set_message(
+                    running
+                        .iter()
+                        .map(|(p, progress)| match progress {
+                            Some(progress) => format!("{p} ({progress})"),
+                            None => p.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                );
             }
             bar.finish_and_clear();
         }))
     } else {
         None
     };
-    buf.for_each(|_| async {}).await; // wait till its done
+    let outcomes = buf.collect::<Vec<_>>().await; // wait till its done
     drop(tx); // drop the transmitter to break the reciever loop
     if bar_or_info {
         handler.unwrap().join().unwrap();
+    }
+    let up_to_date = outcomes
+        .iter()
+        .filter(|o| matches!(o, Outcome::UpToDate))
+        .count();
+    let mut modified = vec![];
+    let mut links = vec![];
+    let staged = outcomes
+        .into_iter()
+        .filter_map(|o| match o {
+            Outcome::Staged(rel, m) => {
+                modified.extend(m);
+                Some(rel)
+            }
+            Outcome::Link(p, m) => {
+                modified.extend(m);
+                links.push(p);
+                None
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let updated = staged.len();
+    if !force {
+        modified.extend(modified_orphans(cfg, cwd));
+    }
+    if !modified.is_empty() {
+        protect_modified(&modified, cwd);
+    }
+    staging
+        .commit(staged)
+        .expect("Installing packages should work (addons/ was left as it was)");
+    // links cant be rolled back, so they come last
+    for p in links {
+        p.link(cwd).expect("Linking local package should work");
+    }
+    prune(cfg, false, true, v, cwd); // already asked
+    register(cfg, v, cwd);
+    for collision in verify::class_name_collisions(cfg, cwd) {
+        eprintln!("{:>12} {collision}", putils::warn());
+    }
+    if bar_or_info {
         println!(
-            "{:>12} updated {} package{} in {}",
+            "{:>12} {} up to date, {} updated in {}",
             putils::green("Finished"),
-            HumanCount(p_count),
-            if p_count > 0 { "s" } else { "" },
+            HumanCount(up_to_date as u64),
+            HumanCount(updated as u64),
             HumanDuration(BEGIN.elapsed())
         )
     }
 }
 
+/// Updates project.godot after installing: enables the editor plugins of the packages that want it (`plugin: true`),
+/// and registers the autoloads packages declare.
+/// Only direct dependencies are registered: indirect ones are libraries for them,
+/// and several versions of one would fight over the same autoload names.
+fn register(cfg: &mut ConfigFile, v: Verbosity, cwd: &Path) {
+    let Some(mut project) = project::Project::read(cwd) else {
+        return;
+    };
+    let (plugins, autoloads) = registrations(cfg, |_| cwd.to_path_buf());
+    let enabled = project.enable_plugins(&plugins);
+    let (added, clashes) = project.add_autoloads(&autoloads, cwd);
+    for (name, path) in clashes {
+        eprintln!(
+            "{:>12} autoload {name} already exists ({path}), not registering it",
+            putils::warn()
+        );
+    }
+    if enabled.is_empty() && added.is_empty() {
+        return;
+    }
+    project
+        .write(cwd)
+        .expect("Should be able to write project.godot");
+    if v.info() {
+        for p in enabled {
+            println!("{:>12} {p}", putils::green("Enabled"));
+        }
+        for name in added {
+            println!("{:>12} autoload {name}", putils::green("Registered"));
+        }
+    }
+}
+
+/// The editor plugins (their `plugin.cfg`s) and autoloads [register] wants in project.godot.
+/// `root` says where the files of a package are (the project, or a staging directory).
+fn registrations(
+    cfg: &ConfigFile,
+    root: impl Fn(&Package) -> PathBuf,
+) -> (Vec<String>, Vec<(String, String)>) {
+    let plugins = cfg
+        .packages
+        .iter()
+        .filter(|p| p.plugin)
+        .flat_map(|p| project::plugin_cfgs(&p.download_dir(Path::new("")), &root(p)))
+        .collect();
+    let autoloads = cfg
+        .packages
+        .iter()
+        .flat_map(|p| p.autoloads(&root(p)))
+        .collect();
+    (plugins, autoloads)
+}
+
+/// Removes the editor plugins and autoloads in `dirs` (relative to `cwd`) from project.godot, as they are about to be removed.
+fn unregister(dirs: &[PathBuf], v: Verbosity, cwd: &Path) {
+    let Some(mut project) = project::Project::read(cwd) else {
+        return;
+    };
+    let dirs = dirs
+        .iter()
+        .map(|d| project::res_path(d))
+        .collect::<Vec<_>>();
+    let disabled = project.disable_plugins_in(&dirs);
+    let removed = project.remove_autoloads_in(&dirs);
+    if disabled.is_empty() && removed.is_empty() {
+        return;
+    }
+    project
+        .write(cwd)
+        .expect("Should be able to write project.godot");
+    if v.info() {
+        for p in disabled {
+            println!("{:>12} {p}", putils::green("Disabled"));
+        }
+        for name in removed {
+            println!("{:>12} autoload {name}", putils::green("Removed"));
+        }
+    }
+}
+
+/// Finds the package `spec` in `cfg`: a name, or `name@version` for when several versions of it are installed.
+fn find_package(cfg: &mut ConfigFile, spec: &str) -> anyhow::Result<Package> {
+    // the first @ is the one of the scope
+    let (name, version) = match spec.get(1..).and_then(|s| s.rsplit_once('@')) {
+        Some((n, v)) => (&spec[..n.len() + 1], Some(v)),
+        None => (spec, None),
+    };
+    let mut found = cfg
+        .collect()
+        .into_iter()
+        .filter(|p| p.name == name)
+        .filter(|p| version.is_none_or(|v| p.manifest.version.to_string() == v))
+        .collect::<Vec<_>>();
+    found.sort();
+    match found.len() {
+        0 => Err(anyhow::anyhow!("{spec} is not a dependency")),
+        1 => Ok(found.remove(0)),
+        _ => Err(anyhow::anyhow!(
+            "{name} is installed at several versions ({}), pick one with {name}@<version>",
+            found
+                .iter()
+                .map(|p| p.manifest.version.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Asks what to do with files in addons/ that were edited (or deleted) since installing, as updating would overwrite them.
+/// Either aborts (panics), or backs them up to `.gpm-backup/<timestamp>/`.
+fn protect_modified(modified: &[PathBuf], cwd: &Path) {
+    eprintln!(
+        "{:>12} these files were modified since installing, and would be overwritten:",
+        putils::warn()
+    );
+    for f in modified {
+        let deleted = if cwd.join(f).exists() {
+            ""
+        } else {
+            " (deleted)"
+        };
+        eprintln!("{:>12} {}{deleted}", "", f.display());
+    }
+    let choice = putils::select(
+        &["Abort", "Back up the modified files and continue"],
+        "What now? (pass --force to skip this check)",
+        0,
+    )
+    .unwrap_or(0); // abort if we cant ask
+    if choice == 0 {
+        panic!("Aborted, nothing was changed");
+    }
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let backup = cwd.join(".gpm-backup").join(stamp.to_string());
+    // deleted files have nothing to back up
+    for f in modified.iter().filter(|f| cwd.join(f).exists()) {
+        let dst = backup.join(f);
+        create_dir_all(dst.parent().unwrap()).expect("Should be able to create backup dir");
+        copy(cwd.join(f), dst).expect("Should be able to back up modified file");
+    }
+    println!(
+        "{:>12} modified files to {}",
+        putils::green("Backed up"),
+        backup.display()
+    );
+}
+
 /// Recursively deletes empty directories.
 /// With this fs tree:
 /// ```
@@ -415,6 +922,11 @@ fn purge(cfg: &mut ConfigFile, v: Verbosity, cwd: &Path) {
             panic!("No packages installed (use \"gpm --update\" to install packages)")
         };
     };
+    let dirs = packages
+        .iter()
+        .map(|p| p.download_dir(Path::new("")))
+        .collect::<Vec<_>>();
+    unregister(&dirs, v, cwd);
     let p_count = packages.len() as u64;
     let bar;
     if v.bar() {
@@ -441,7 +953,7 @@ fn purge(cfg: &mut ConfigFile, v: Verbosity, cwd: &Path) {
 
     // run multiple times because the algorithm goes from top to bottom, stupidly.
     for _ in 0..3 {
-        if let Err(e) = recursive_delete_empty(&cwd.join("addons"), cwd) {
+        if let Err(e) = recursive_delete_empty(&cwd.join(&cfg.layout.root), cwd) {
             eprintln!("{e}")
         }
     }
@@ -456,6 +968,53 @@ fn purge(cfg: &mut ConfigFile, v: Verbosity, cwd: &Path) {
     }
 }
 
+/// The files in the orphaned directories (see [verify::orphaned]) that were edited since installing, relative to `cwd`.
+fn modified_orphans(cfg: &mut ConfigFile, cwd: &Path) -> Vec<PathBuf> {
+    verify::orphaned(cfg, cwd)
+        .iter()
+        .flat_map(|d| meta::modified_files(d, cwd))
+        .collect()
+}
+
+/// Deletes the orphaned directories, see [verify::orphaned].
+/// Unless `force`, asks what to do with edited files first (see [protect_modified]).
+/// Their editor plugins and autoloads are removed from project.godot. Returns how many there were.
+fn prune(cfg: &mut ConfigFile, dry_run: bool, force: bool, v: Verbosity, cwd: &Path) -> usize {
+    let orphans = verify::orphaned(cfg, cwd);
+    if !dry_run && !force {
+        let modified = modified_orphans(cfg, cwd);
+        if !modified.is_empty() {
+            protect_modified(&modified, cwd);
+        }
+    }
+    if !dry_run {
+        let dirs = orphans
+            .iter()
+            .map(|d| d.strip_prefix(cwd).unwrap().to_path_buf())
+            .collect::<Vec<_>>();
+        unregister(&dirs, v, cwd);
+    }
+    let root = cwd.join(&cfg.layout.root);
+    for dir in &orphans {
+        let rel = dir.strip_prefix(cwd).unwrap().display();
+        if dry_run {
+            println!("{:>12} {rel}", putils::green("Would prune"));
+            continue;
+        }
+        if v.info() {
+            println!("{:>12} {rel}", putils::green("Pruning"));
+        }
+        remove_dir_all(dir).expect("Should be able to remove orphaned dependency");
+        // remove the now empty name (and scope) directories
+        for parent in dir.ancestors().skip(1).take(2) {
+            if parent == root || remove_dir(parent).is_err() {
+                break;
+            }
+        }
+    }
+    orphans.len()
+}
+
 async fn tree(
     cfg: &mut ConfigFile,
     charset: CharSet,
@@ -491,6 +1050,7 @@ async fn tree(
     tree.push_str(format!("{} dependencies", HumanCount(count)).as_str());
 
     #[async_recursion]
+    #[allow(clippy::too_many_arguments)]
     async fn iter(
         packages: &mut Vec<Package>,
         prefix: &str,
@@ -618,7 +1178,14 @@ async fn init(mut packages: Vec<Package>, client: Client, cwd: &Path) -> Result<
     if !c.packages.is_empty()
         && putils::confirm("Would you like to install your new packages?", true)?
     {
-        update(&mut c, true, Verbosity::Normal, client.clone(), cwd).await;
+        update(
+            &mut c,
+            UpdateOptions::default(),
+            Verbosity::Normal,
+            client.clone(),
+            cwd,
+        )
+        .await;
     };
     println!("Goodbye!");
     Ok(())
@@ -645,7 +1212,10 @@ mod test_utils {
         (
             tmp_dir,
             TestServer::spawn(sock).await,
-            mkclient(format!("http://{sock}")),
+            mkclient(
+                format!("http://{sock}"),
+                format!("http://{sock}/asset-library/api"),
+            ),
         )
     }
 
@@ -654,7 +1224,9 @@ mod test_utils {
             .unwrap()
             .filter_map(|s| {
                 let p = &s.unwrap();
-                p.is_file().then(|| {
+                // install markers hold the (random) address of the test server
+                let marker = p.ends_with(crate::package::meta::META_FILE);
+                (p.is_file() && !marker).then(|| {
                     let mut hasher = Sha256::new();
                     hasher.update(read(p).unwrap());
                     format!("{:x}", &hasher.finalize())
@@ -673,7 +1245,17 @@ async fn gpm() {
     let cfg_file =
         &mut config_file::ConfigFile::new(&r#"packages: {"@bendn/test":2.0.10}"#.into(), c.clone())
             .await;
-    update(cfg_file, false, Verbosity::Verbose, c.clone(), t.0.path()).await;
+    update(
+        cfg_file,
+        UpdateOptions {
+            modify: false,
+            ..Default::default()
+        },
+        Verbosity::Verbose,
+        c.clone(),
+        t.0.path(),
+    )
+    .await;
     assert_eq!(test_utils::hashd(&t.0.path().join("addons")).join("|"), "1c2fd93634817a9e5f3f22427bb6b487520d48cf3cbf33e93614b055bcbd1329|8e77e3adf577d32c8bc98981f05d40b2eb303271da08bfa7e205d3f27e188bd7|a625595a71b159e33b3d1ee6c13bea9fc4372be426dd067186fe2e614ce76e3c|c5566e4fbea9cc6dbebd9366b09e523b20870b1d69dc812249fccd766ebce48e|c5566e4fbea9cc6dbebd9366b09e523b20870b1d69dc812249fccd766ebce48e|c850a9300388d6da1566c12a389927c3353bf931c4d6ea59b02beb302aac03ea|d060936e5f1e8b1f705066ade6d8c6de90435a91c51f122905a322251a181a5c|d711b57105906669572a0e53b8b726619e3a21463638aeda54e586a320ed0fc5|d794f3cee783779f50f37a53e1d46d9ebbc5ee7b37c36d7b6ee717773b6955cd|e4f9df20b366a114759282209ff14560401e316b0059c1746c979f478e363e87");
     purge(cfg_file, Verbosity::Verbose, t.0.path());
     assert_eq!(
@@ -697,6 +1279,125 @@ async fn gpm() {
     );
 }
 
+#[tokio::test]
+async fn prune_modified() {
+    let t = test_utils::mktemp().await;
+    let (c, cwd) = (t.2, t.0.path());
+    let cfg = &mut ConfigFile::new(&r#"packages: {"@bendn/test":2.0.10}"#.into(), c.clone()).await;
+    update(
+        cfg,
+        UpdateOptions::default(),
+        Verbosity::Quiet,
+        c.clone(),
+        cwd,
+    )
+    .await;
+    // a old version, that was edited
+    let deps = cwd.join("addons/__gpm_deps/@bendn/gdcli");
+    package::copy_dir(&deps.join("1.2.5"), &deps.join("1.2.0")).unwrap();
+    let edited = read_dir(deps.join("1.2.0"))
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.extension().is_some_and(|e| e == "gd"))
+        .unwrap();
+    write(&edited, "extends Node # edited\n").unwrap();
+    assert_eq!(
+        modified_orphans(cfg, cwd),
+        [edited.strip_prefix(cwd).unwrap()]
+    );
+    let force = UpdateOptions {
+        force: true,
+        ..Default::default()
+    };
+    update(cfg, force, Verbosity::Quiet, c, cwd).await;
+    assert!(!deps.join("1.2.0").exists());
+    assert!(deps.join("1.2.5").exists());
+}
+
+#[tokio::test]
+async fn find() {
+    let t = test_utils::mktemp().await;
+    let c = t.2;
+    let cfg = &mut ConfigFile::new(
+        &r#"packages: {"@bendn/test":2.0.10, "@bendn/gdcli":1.2.0}"#.into(),
+        c.clone(),
+    )
+    .await;
+    let found = |cfg: &mut ConfigFile, spec| find_package(cfg, spec).map(|p| p.to_string());
+    assert_eq!(found(cfg, "@bendn/test").unwrap(), "@bendn/test@2.0.10");
+    assert_eq!(
+        found(cfg, "@bendn/gdcli@1.2.5").unwrap(),
+        "@bendn/gdcli@1.2.5"
+    );
+    assert!(found(cfg, "@bendn/gdcli")
+        .unwrap_err()
+        .to_string()
+        .contains("1.2.0, 1.2.5"));
+    assert!(found(cfg, "@bendn/gdcli@9.9.9").is_err());
+    assert!(found(cfg, "nope").is_err());
+}
+
+#[tokio::test]
+async fn autoloads() {
+    let t = test_utils::mktemp().await;
+    let (c, cwd) = (t.2, t.0.path());
+    let src = cwd.join("my-addon");
+    create_dir_all(&src).unwrap();
+    write(
+        src.join("package.json"),
+        r#"{"name":"my-addon","version":"0.1.0","godot":{"autoloads":{"Mine":"mine.gd"}}}"#,
+    )
+    .unwrap();
+    write(src.join("mine.gd"), "extends Node\n").unwrap();
+    // a package that was removed from the config
+    let dropped = cwd.join("addons/@bendn/dropped");
+    create_dir_all(&dropped).unwrap();
+    write(dropped.join(package::meta::META_FILE), "{}").unwrap();
+    write(dropped.join("d.gd"), "extends Node\n").unwrap();
+    write(
+        cwd.join("project.godot"),
+        "config_version=5\n\n[autoload]\n\nDropped=\"*res://addons/@bendn/dropped/d.gd\"\n",
+    )
+    .unwrap();
+    let cfg = &mut ConfigFile::new(
+        &format!(r#"packages: {{"my-addon": "file:{}"}}"#, src.display()),
+        c.clone(),
+    )
+    .await;
+    let force = UpdateOptions {
+        force: true,
+        ..Default::default()
+    };
+    update(cfg, force, Verbosity::Quiet, c, cwd).await;
+    let project = project::Project::read(cwd).unwrap();
+    assert_eq!(
+        project.get("autoload", "Mine"),
+        Some("\"*res://addons/my-addon/mine.gd\"")
+    );
+    assert_eq!(project.get("autoload", "Dropped"), None);
+    assert!(!dropped.exists());
+}
+
+#[test]
+fn project_root() {
+    let t = tempfile::TempDir::new().unwrap();
+    let game = t.path().join("game");
+    let sub = game.join("scenes/levels");
+    create_dir_all(&sub).unwrap();
+    assert_eq!(find_root(&sub, Path::new("godot.package")), None);
+    write(game.join("project.godot"), "config_version=5\n").unwrap();
+    assert_eq!(
+        find_root(&sub, Path::new("godot.package")),
+        Some(game.clone())
+    );
+    write(game.join("scenes/godot.package"), "packages: {}").unwrap();
+    assert_eq!(
+        find_root(&sub, Path::new("godot.package")),
+        Some(game.join("scenes"))
+    );
+    assert_eq!(find_root(&sub, Path::new("-")), Some(game));
+}
+
 /// Print utilities.
 /// Remember to use {:>12}
 pub mod putils {
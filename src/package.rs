@@ -1,22 +1,32 @@
 use crate::archive::*;
+use crate::assetlib::Asset;
 use crate::cache::CacheEntry;
+use crate::config_file::{ConfigFile, ConfigType};
 use crate::conversions::TryIntoAsync;
+use crate::download::download;
+use crate::git::GitRepo;
+use crate::transaction::Transaction;
 use crate::Client;
 
 use anyhow::bail;
 use anyhow::{anyhow, Context, Result};
 use async_recursion::async_recursion;
-use regex::{Captures, Regex};
 use semver_rs::{Range, Version};
 use serde::Serialize;
 use sha1::{Digest, Sha1};
-use std::fs::{read_dir, read_to_string, remove_dir_all, write};
+use std::fs::{copy, create_dir_all, read_dir, read_to_string, remove_dir_all, write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::{collections::HashMap, fmt};
 
+pub mod layout;
+pub mod meta;
 pub mod parsing;
+pub mod rewrite;
+use layout::Layout;
+use meta::{Meta, Origin};
 use parsing::*;
+use rewrite::{rewriters, Rewriter};
 
 type DepMap = HashMap<String, PathBuf>;
 
@@ -34,6 +44,18 @@ pub struct Package {
     pub manifest: Manifest,
     #[serde(rename = "version")]
     pub _lockfile_version_string: String, // for lockfile, do not use
+    /// The subdirectory of the archive that becomes the package root, see [find_addon_root].
+    #[serde(skip)]
+    pub root: Option<PathBuf>,
+    /// Globs of files to not install, see [Filter].
+    #[serde(skip)]
+    pub exclude: Vec<String>,
+    /// Wether to enable the editor plugins of this package, see [crate::project].
+    #[serde(skip)]
+    pub plugin: bool,
+    /// Where to install, see [Layout].
+    #[serde(skip)]
+    pub layout: Layout,
 }
 
 #[derive(Clone, Eq, Ord, PartialEq, PartialOrd, Default, Debug, Serialize, Hash)]
@@ -45,6 +67,9 @@ pub struct Manifest {
     pub dependencies: Vec<Package>,
     #[serde(skip)]
     pub version: Version,
+    /// The godot versions this package supports, see [crate::project::compatible].
+    #[serde(skip)]
+    pub engine: Option<String>,
 }
 
 #[macro_export]
@@ -90,6 +115,9 @@ impl Package {
             // i forgot what this is for
             return Self::new_no_version(name, client).await;
         }
+        if version.starts_with("file:") {
+            return Self::get_local(name, version.to_owned(), client).await;
+        }
         let r = ctx!(
             Range::new(version).parse(),
             "parsing version range {version} for {name}"
@@ -97,17 +125,24 @@ impl Package {
         if name.starts_with("http") {
             return Self::get_tarball(name, version.to_owned(), &r, client).await;
         }
+        if GitRepo::is_git(&name) {
+            return Self::get_git(name, version.to_owned(), &r, client).await;
+        }
+        if Asset::is_asset(&name) {
+            return Self::get_asset(name, version.to_owned(), &r, client).await;
+        }
 
         if let Some(got) = client.cache().get_mut(&name) {
             let mut vers = got.clone(); // clone to remove references to dashmap
             drop(got); // drop reference (let x = x doesnt drop original x until scope ends)
-            if let Some(mut find) = vers.find_version(&r) {
+            if let Some(mut find) = vers.find_version(&r, client.engine()) {
                 // find is a reference to vers which is cloned (not ref to dashmap)
                 // this block was supposed to be
                 // Ok(find.parse(...).await?.get_package())
                 // but then it deadlocked because get_package() would recurse
                 find.parse(client.clone(), name.clone()).await?;
                 let p = find.get_package();
+                p.warn_incompatible(&client);
                 client.cache_ref().insert(
                     name,
                     find.key().clone(),
@@ -127,9 +162,10 @@ impl Package {
             e.insert_packument(packument).clone()
         };
         // do it again with the new entrys inserted
-        if let Some(mut find) = versions.find_version(&r) {
+        if let Some(mut find) = versions.find_version(&r, client.engine()) {
             find.parse(client.clone(), name.clone()).await?;
             let p = find.get_package();
+            p.warn_incompatible(&client);
             client
                 .cache()
                 .insert(name, find.key().clone(), std::mem::take(find.value_mut()));
@@ -141,6 +177,17 @@ impl Package {
         );
     }
 
+    /// Warns if this package does not support the godot version of the project.
+    fn warn_incompatible(&self, client: &Client) {
+        if !crate::project::compatible(self.manifest.engine.as_deref(), client.engine()) {
+            eprintln!(
+                "{:>12} {self} needs godot {}, which this project does not use (no compatible version matched)",
+                crate::putils::warn(),
+                self.manifest.engine.as_deref().unwrap_or_default(),
+            );
+        }
+    }
+
     /// Create a package from a [str]. see also [ParsedPackage].
     #[allow(dead_code)] // used for tests
     pub async fn create_from_str(s: &str, client: Client) -> Result<Package> {
@@ -157,21 +204,152 @@ impl Package {
         client: Client,
     ) -> Result<Package> {
         if let Some(mut v) = client.cache().get_mut(&uri) {
-            if let Some(e) = v.find_version(range) {
+            if let Some(e) = v.find_version(range, client.engine()) {
                 return Ok(e.get_package()); // no recursion, very safe
             }
         }
 
-        let resp = ctx!(get!(client.clone(), "{uri}"), "getting tarball {uri}")?;
-        let ty = uri.split('.').last().unwrap_or("zip");
-        let bytes = resp.bytes().await?.to_vec();
-        let mut entry = CacheEntry::from(CompressionType::from(ty, bytes, uri.clone()));
+        let d = ctx!(
+            download(&client, &uri, |_, _| {}).await,
+            "getting tarball {uri}"
+        )?;
+        let ty = CompressionType::from_file(d.file, uri.clone())?;
+        let mut entry = CacheEntry::from(ty);
         entry.parse(client.clone(), uri.clone()).await?;
         let p = entry.get_package();
         client.cache().insert(uri, version.clone(), entry);
         Ok(p)
     }
 
+    /// Creates a [Package] from a git repository, pinning the ref to a commit.
+    pub async fn get_git(
+        spec: String,
+        version: String,
+        range: &Range,
+        client: Client,
+    ) -> Result<Package> {
+        if let Some(mut v) = client.cache().get_mut(&spec) {
+            if let Some(e) = v.find_version(range, client.engine()) {
+                return Ok(e.get_package());
+            }
+        }
+
+        let repo = ctx!(GitRepo::parse(&spec), "parsing git dependency {spec}")?;
+        let (commit, file) = ctx!(repo.archive().await, "getting git repository {spec}")?;
+        let pinned = repo.pin(&commit);
+        let file = ArchiveFile::from(file);
+        let mut entry = CacheEntry::from(CompressionType::Gzip(Data::new_file(
+            file.clone(),
+            pinned.clone(),
+        )));
+        entry.parse(client.clone(), spec.clone()).await?;
+        let mut p = entry.get_package();
+        // keep the archive, so installing does not have to clone again
+        p.manifest.tarball = CompressionType::Git(Data::new_file(file, pinned));
+        client.cache().insert(spec, version, p.clone().into());
+        Ok(p)
+    }
+
+    /// Creates a [Package] from the Godot Asset Library.
+    /// The asset library only serves the latest version of an asset, so the range must match it.
+    pub async fn get_asset(
+        spec: String,
+        version: String,
+        range: &Range,
+        client: Client,
+    ) -> Result<Package> {
+        if let Some(mut v) = client.cache().get_mut(&spec) {
+            if let Some(e) = v.find_version(range, client.engine()) {
+                return Ok(e.get_package());
+            }
+        }
+
+        let asset = Asset::get(&spec, &client).await?;
+        let v = asset.version()?;
+        if !range.test(&v) {
+            bail!(
+                "{} is at version {v}, which does not match {version} (only the latest version is available)",
+                asset.title,
+            );
+        }
+        let d = ctx!(
+            download(&client, &asset.download_url, |_, _| {}).await,
+            "downloading asset {}",
+            asset.title
+        )?;
+        // keep the download, so installing does not have to download it again
+        let ty = CompressionType::from_file(d.file, asset.download_url.clone())?;
+        let root = find_addon_root(&Archive::new(ty.clone())?.paths());
+        let name = root
+            .as_ref()
+            .and_then(|r| r.file_name())
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| asset.slug());
+        let mut p = Package::from_manifest(
+            Manifest {
+                shasum: Some(asset.download_hash).filter(|h| !h.is_empty()),
+                tarball: ty,
+                dependencies: vec![],
+                version: v,
+                engine: None,
+            },
+            name,
+        );
+        p.root = root;
+        client.cache().insert(spec, version, p.clone().into());
+        Ok(p)
+    }
+
+    /// Creates a [Package] from a local directory (`file:../my-addon`), reading its package.json.
+    pub async fn get_local(name: String, spec: String, client: Client) -> Result<Package> {
+        let path = Path::new(spec.strip_prefix("file:").unwrap());
+        let contents = ctx!(
+            read_to_string(path.join("package.json")),
+            "reading package.json of {spec}"
+        )?;
+        let mut p = ctx!(
+            ConfigFile::parse(&contents, ConfigType::JSON, client).await,
+            "parsing package.json of {spec}"
+        )?
+        .into_package(CompressionType::Local(Data::new_uri(spec)))?;
+        p.name = name;
+        for dep in &mut p.manifest.dependencies {
+            dep.indirect = true;
+        }
+        Ok(p)
+    }
+
+    /// Is this a local (`file:`) package?
+    pub fn is_local(&self) -> bool {
+        matches!(self.manifest.tarball, CompressionType::Local(_))
+    }
+
+    /// The source directory of a local package.
+    fn local_dir(&self) -> Option<&Path> {
+        match &self.manifest.tarball {
+            CompressionType::Local(d) => Some(Path::new(d.uri.strip_prefix("file:").unwrap())),
+            _ => None,
+        }
+    }
+
+    /// Symlinks this local [Package] into its download directory, instead of copying it.
+    pub fn link(&self, cwd: &Path) -> Result<()> {
+        let src = self
+            .local_dir()
+            .ok_or_else(|| anyhow!("{self} is not a local package"))?;
+        let src = ctx!(
+            self.root
+                .as_ref()
+                .map_or(src.to_path_buf(), |r| src.join(r))
+                .canonicalize(),
+            "finding source of {self}"
+        )?;
+        self.purge(cwd);
+        let dst = self.download_dir(cwd);
+        create_dir_all(dst.parent().unwrap())?;
+        ctx!(crate::link::symlink_dir(&src, &dst), "linking {self}")
+    }
+
     /// Creates a new [Package] from a name, gets the latest version from registry/name.
     pub async fn new_no_version(name: String, client: Client) -> Result<Package> {
         const MARKER: &str = "🐢"; // latest
@@ -206,9 +384,61 @@ impl Package {
         self.download_dir(cwd).exists()
     }
 
+    /// Returns wether this exact package is already installed, according to its install marker.
+    /// Local packages are never up to date, as their source may have changed.
+    pub fn is_up_to_date(&self, cwd: &Path) -> bool {
+        !self.is_local()
+            && !self.is_linked(cwd)
+            && Meta::read(&self.download_dir(cwd))
+                .is_some_and(|m| m.origin == Origin::of(self, cwd))
+    }
+
+    /// The files of the installed package that were edited, added or deleted since installing, relative to `cwd`.
+    /// Packages without a install marker are assumed to be unmodified.
+    pub fn modified_files(&self, cwd: &Path) -> Vec<PathBuf> {
+        if self.is_linked(cwd) {
+            return vec![];
+        }
+        meta::modified_files(&self.download_dir(cwd), cwd)
+    }
+
+    /// The autoloads the installed package declares in its package.json, as (name, `res://` path).
+    pub fn autoloads(&self, cwd: &Path) -> Vec<(String, String)> {
+        let Ok(json) = read_to_string(self.download_dir(cwd).join("package.json")) else {
+            return vec![];
+        };
+        let m: InstalledManifest = match serde_json::from_str(&json) {
+            Ok(m) => m,
+            Err(e) => {
+                eprintln!(
+                    "{:>12} {self}: cant read package.json: {e}",
+                    crate::putils::warn()
+                );
+                return vec![];
+            }
+        };
+        let dir = self.download_dir(Path::new(""));
+        m.godot
+            .autoloads
+            .into_iter()
+            .map(|(name, path)| {
+                let path = dir.join(path.trim_start_matches("./"));
+                (name, crate::project::res_path(&path))
+            })
+            .collect()
+    }
+
+    /// Returns wether this package is a symlink (see `gpm link` and `update --link`).
+    pub fn is_linked(&self, cwd: &Path) -> bool {
+        crate::link::is_link(&self.download_dir(cwd))
+    }
+
     /// Deletes this [Package].
+    /// Linked packages only have their link removed, never the linked directory.
     pub fn purge(&self, cwd: &Path) {
-        if self.is_installed(cwd) {
+        if self.is_linked(cwd) {
+            crate::link::unlink(&self.download_dir(cwd)).expect("Should be able to remove link");
+        } else if self.is_installed(cwd) {
             remove_dir_all(self.download_dir(cwd)).expect("Should be able to remove download dir");
         }
     }
@@ -216,37 +446,89 @@ impl Package {
     /// Installs this [Package] to a download directory,
     /// depending on wether this package is a direct dependency or not.
     pub async fn download(&mut self, client: Client, cwd: &Path) {
-        self.purge(cwd);
-        let bytes = get!(client.clone(), "{}", &self.manifest.tarball)
-            .expect("Tarball download should work")
-            .bytes()
-            .await
-            .unwrap()
-            .to_vec();
-
-        let mut hasher = Sha1::new();
-        hasher.update(&bytes);
-        if let Some(sha) = &self.manifest.shasum {
-            assert_eq!(
-                sha,
-                &format!("{:x}", hasher.finalize()),
-                "Tarball did not match checksum!"
-            );
+        self.download_with(client, cwd, |_, _| {}).await
+    }
+
+    /// Like [Package::download], but reports the downloaded bytes (and the total, if known) to `progress`.
+    /// The package is staged first, and then moved in place (see [Transaction]),
+    /// so a failing install does not leave a half unpacked package behind.
+    pub async fn download_with(
+        &mut self,
+        client: Client,
+        cwd: &Path,
+        progress: impl FnMut(u64, Option<u64>),
+    ) {
+        let tx = Transaction::new(cwd).expect("Should be able to create staging dir");
+        self.stage(client, &tx, progress).await;
+        self.mark_staged(&tx, cwd);
+        tx.commit([self.download_dir(Path::new(""))])
+            .expect("Should be able to move package in place");
+    }
+
+    /// Unpacks this [Package] into the staging directory of `tx`.
+    /// Returns the download directory (relative to the project), to pass to [Transaction::commit].
+    pub async fn stage(
+        &self,
+        client: Client,
+        tx: &Transaction,
+        progress: impl FnMut(u64, Option<u64>),
+    ) -> PathBuf {
+        let rel = self.download_dir(Path::new(""));
+        let dst = tx.staged(&rel);
+        create_dir_all(&dst).expect("Should be able to create staging dir");
+        self.unpack_to(client, &dst, progress).await;
+        rel
+    }
+
+    async fn unpack_to(&self, client: Client, dst: &Path, progress: impl FnMut(u64, Option<u64>)) {
+        if let Some(src) = self.local_dir() {
+            let src = self
+                .root
+                .as_ref()
+                .map_or(src.to_path_buf(), |r| src.join(r));
+            copy_dir(&src, dst).expect("Copying local package should work");
+            return;
         }
-        // println!(
-        //     "(\"{}\", hex::decode(\"{}\").unwrap()),",
-        //     self.manifest.tarball.replace(&(client.registry + "/"), ""),
-        //     hex::encode(&bytes)
-        // );
-        let ty = match self.manifest.tarball.clone() {
-            CompressionType::Gzip(_) => CompressionType::Gzip(Data::new_bytes(bytes)),
-            CompressionType::Zip(_) => CompressionType::Zip(Data::new_bytes(bytes)),
-            _ => unreachable!(),
+        let want = self.manifest.shasum.as_deref().unwrap_or_default();
+        let (ty, sha) = if let Some(d) = self.manifest.tarball.data().filter(|d| d.file.is_some()) {
+            // already got when resolving
+            let file = d.file.clone().unwrap();
+            let sha = digest(file.path(), want).expect("Should be able to read archive");
+            (CompressionType::from_file(file, d.uri.clone()), sha)
+        } else if let CompressionType::Git(d) = &self.manifest.tarball {
+            let repo = GitRepo::parse(&d.uri).expect("Git uri should be valid");
+            let file = repo.archive().await.expect("Git archive should work").1;
+            let sha = digest(file.path(), want).expect("Should be able to read archive");
+            (CompressionType::from_file(file, d.uri.clone()), sha)
+        } else {
+            let uri = self.manifest.tarball.to_string();
+            let d = download(&client, &uri, progress)
+                .await
+                .expect("Tarball download should work");
+            (CompressionType::from_file(d.file, uri), d.sha1)
         };
-        Archive::new(ty)
-            .unwrap()
-            .unpack(&self.download_dir(cwd))
+        if let Some(want) = &self.manifest.shasum {
+            assert_eq!(want, &sha, "Tarball did not match checksum!");
+        }
+        let archive = Archive::new(ty.expect("Tarball should be a archive"))
+            .expect("Tarball should be readable");
+        let paths = archive.paths();
+        let root = self.root.clone().or_else(|| find_plugin_root(&paths));
+        let filter = Filter::new(
+            &paths,
+            archive.file("package.json"),
+            archive.file(".npmignore"),
+            &self.exclude,
+        )
+        .expect("Filter should be valid");
+        archive
+            .unpack(dst, root.as_deref(), &filter)
             .expect("Tarball should unpack");
+        // package.json is always installed, even if its outside of the root
+        if let (Some(_), Some(manifest)) = (&root, archive.file("package.json")) {
+            std::fs::write(dst.join("package.json"), manifest)
+                .expect("Should be able to write package.json");
+        }
     }
 
     pub async fn get_packument(client: Client, name: &str) -> Result<Packument> {
@@ -285,86 +567,39 @@ impl Package {
 
     /// The download directory if this package is a direct dep.
     fn direct_download_dir(&self, cwd: &Path) -> PathBuf {
-        cwd.join("addons").join(self.name.clone())
+        self.layout.direct(cwd, &self.name)
     }
 
     /// The download directory if this package is a indirect dep.
     fn indirect_download_dir(&self, cwd: &Path) -> PathBuf {
-        cwd.join("addons")
-            .join("__gpm_deps")
-            .join(self.name.clone())
-            .join(self.manifest.version.to_string())
+        self.layout
+            .indirect(cwd, &self.name, &self.manifest.version.to_string())
     }
 }
 
 // package modification block
 impl Package {
-    /// Modifies the loads of a GDScript script.
-    /// ```gdscript
-    /// extends Node
-    ///
-    /// const Wow = preload("res://addons/my_awesome_addon/wow.gd")
-    /// ```
-    /// =>
-    /// ```gdscript
-    /// # --snip--
-    /// const Wow = preload("res://addons/__gpm_deps/my_awesome_addon/wow.gd")
-    /// ```
-    fn modify_script_loads(&self, t: &str, cwd: &Path, dep_map: &DepMap) -> String {
-        lazy_static::lazy_static! {
-            static ref SCRIPT_LOAD_R: Regex = Regex::new("(pre)?load\\([\"']([^)]+)['\"]\\)").unwrap();
-        }
-        SCRIPT_LOAD_R
-            .replace_all(t, |c: &Captures| {
-                let p = Path::new(c.get(2).unwrap().as_str());
-                let res = self.modify_load(p.strip_prefix("res://").unwrap_or(p), cwd, dep_map);
-                let preloaded = if c.get(1).is_some() { "pre" } else { "" };
-                if res == p {
-                    format!("{preloaded}load('{}')", p.display())
-                } else {
-                    format!("{preloaded}load('res://{}')", res.display())
-                }
-            })
-            .to_string()
-    }
-
-    /// Modifies the loads of a godot TextResource.
-    /// ```gdresource
-    /// [gd_scene load_steps=1 format=2]
-    ///
-    /// [ext_resource path="res://addons/my_awesome_addon/wow.gd" type="Script" id=1]
-    /// ```
-    /// =>
-    /// ```gdresource
-    /// --snip--
-    /// [ext_resource path="res://addons/__gpm_deps/my_awesome_addon/wow.gd" type="Script" id=1]
-    /// ```
-    fn modify_tres_loads(&self, t: &str, cwd: &Path, dep_map: &DepMap) -> String {
-        lazy_static::lazy_static! {
-            static ref TRES_LOAD_R: Regex = Regex::new(r#"\[ext_resource path="([^"]+)""#).unwrap();
-        }
-        TRES_LOAD_R
-            .replace_all(t, |c: &Captures| {
-                let p = Path::new(c.get(1).unwrap().as_str());
-                let res = self.modify_load(
-                    p.strip_prefix("res://")
-                        .expect("TextResource path should be absolute"),
-                    cwd,
-                    dep_map,
-                );
-                if res == p {
-                    format!(r#"[ext_resource path="{}""#, p.display())
-                } else {
-                    format!(r#"[ext_resource path="res://{}""#, res.display())
-                }
-            })
-            .to_string()
+    /// Modifies a (`res://`) path, keeping it as is if it does not need changing.
+    /// `dir` is the directory of the file the path is in, and `roots` are where `res://` points to (see [Package::recursive_modify]).
+    fn modify_path(&self, p: &str, dir: &Path, roots: &[PathBuf], dep_map: &DepMap) -> String {
+        let path = Path::new(p);
+        let stripped = path.strip_prefix("res://").unwrap_or(path);
+        let res = self.modify_load(stripped, dir, roots, dep_map);
+        if res == stripped {
+            return p.to_string();
+        }
+        let mut res = format!("res://{}", res.display());
+        // load("res://addons/x/" + file)
+        if p.ends_with('/') && !res.ends_with('/') {
+            res.push('/');
+        }
+        res
     }
 
-    /// The backend for modify_script_loads and modify_tres_loads.
-    fn modify_load(&self, path: &Path, cwd: &Path, dep_map: &DepMap) -> PathBuf {
+    /// The backend for modify_path.
+    fn modify_load(&self, path: &Path, dir: &Path, roots: &[PathBuf], dep_map: &DepMap) -> PathBuf {
         // if it works, skip it
-        if path.exists() || cwd.join(path).exists() {
+        if dir.join(path).exists() || roots.iter().any(|r| r.join(path).exists()) {
             return path.to_path_buf();
         }
         if let Some(c) = path.components().nth(1) {
@@ -382,41 +617,37 @@ impl Package {
     }
 
     /// Recursively modifies a directory.
-    fn recursive_modify(&self, dir: PathBuf, dep_map: &DepMap) -> Result<()> {
+    /// Paths that exist in one of the `roots` (the staged tree, then the project) are kept,
+    /// the others are looked up in the `dep_map`.
+    fn recursive_modify(
+        &self,
+        dir: PathBuf,
+        roots: &[PathBuf],
+        dep_map: &DepMap,
+        rewriters: &[Box<dyn Rewriter>],
+    ) -> Result<()> {
         for entry in read_dir(&dir)? {
             let p = entry?;
             if p.path().is_dir() {
-                self.recursive_modify(p.path(), dep_map)?;
+                self.recursive_modify(p.path(), roots, dep_map, rewriters)?;
                 continue;
             }
 
-            #[derive(PartialEq, Debug)]
-            enum Type {
-                TextResource,
-                GDScript,
-            }
-            if let Some(e) = p.path().extension() {
-                let t = if e == "tres" || e == "tscn" {
-                    Type::TextResource
-                } else if e == "gd" || e == "gdscript" {
-                    Type::GDScript
-                } else {
-                    continue;
-                };
-                let text = read_to_string(p.path())?;
-                write(
-                    p.path(),
-                    match t {
-                        Type::TextResource => self.modify_tres_loads(&text, &dir, dep_map),
-                        Type::GDScript => self.modify_script_loads(&text, &dir, dep_map),
-                    },
-                )?;
+            let path = p.path();
+            let Some(r) = rewriters.iter().find(|r| r.handles(&path)) else {
+                continue;
+            };
+            let text = read_to_string(&path)?;
+            let new = r.rewrite(&text, &path, &|p| self.modify_path(p, &dir, roots, dep_map));
+            if new != text {
+                write(&path, new)?;
             }
         }
         Ok(())
     }
 
-    fn dep_map(&mut self, cwd: &Path) -> Result<DepMap> {
+    /// Where the dependencies (and this package) are installed, by name. Pass a empty `cwd` to get `res://` paths.
+    fn dep_map(&self, cwd: &Path) -> Result<DepMap> {
         let mut dep_map = HashMap::<String, PathBuf>::new();
         fn add(p: &Package, dep_map: &mut DepMap, cwd: &Path) -> Result<()> {
             let d = p.download_dir(cwd);
@@ -439,10 +670,91 @@ impl Package {
         if !self.is_installed(cwd) {
             panic!("Attempting to modify a package that is not installed");
         }
+        if self.is_linked(cwd) {
+            return; // dont touch the source
+        }
+
+        let map = &self.dep_map(Path::new("")).unwrap();
+        self.recursive_modify(
+            self.download_dir(cwd),
+            &[cwd.to_path_buf()],
+            map,
+            &rewriters(&self.layout),
+        )
+        .unwrap();
+    }
+
+    /// Writes the install marker of this staged [Package], see [Meta].
+    /// Do this last, as it records the hashes of the files.
+    pub fn mark_staged(&self, tx: &Transaction, cwd: &Path) {
+        let dir = tx.staged(&self.download_dir(Path::new("")));
+        Meta::of(self, &dir, cwd)
+            .and_then(|m| m.write(&dir))
+            .expect("Should be able to write install marker");
+    }
+
+    /// Like [Package::modify], but for a package staged in `tx`.
+    /// Loads that work in the staged tree (or in `cwd`, for the packages that are not being replaced) are kept.
+    pub fn modify_staged(&mut self, tx: &Transaction, cwd: &Path) {
+        let map = &self.dep_map(Path::new("")).unwrap();
+        self.recursive_modify(
+            tx.staged(&self.download_dir(Path::new(""))),
+            &[tx.staged(Path::new("")), cwd.to_path_buf()],
+            map,
+            &rewriters(&self.layout),
+        )
+        .unwrap();
+    }
+}
 
-        let map = &self.dep_map(cwd).unwrap();
-        self.recursive_modify(self.download_dir(cwd), map).unwrap();
+/// The hex encoded hash of `bytes`, using the algorithm of `like`:
+/// sha256 for asset library hashes, sha1 for everything else (npm shasums).
+fn digest(file: &Path, like: &str) -> std::io::Result<String> {
+    fn hash<D: Digest + std::io::Write>(file: &Path) -> std::io::Result<String> {
+        let mut hasher = D::new();
+        std::io::copy(&mut std::fs::File::open(file)?, &mut hasher)?;
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect())
+    }
+    match like.len() {
+        64 => hash::<sha2::Sha256>(file),
+        _ => hash::<Sha1>(file),
+    }
+}
+
+/// Recursively copies a directory, skipping `.git`.
+/// Symlinks are followed (dev checkouts often link in shared code), except ones that loop back into a parent.
+pub fn copy_dir(src: &Path, dst: &Path) -> std::io::Result<()> {
+    create_dir_all(dst)?;
+    for entry in read_dir(src)? {
+        let entry = entry?;
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        let path = entry.path();
+        let Ok(meta) = path.metadata() else {
+            eprintln!(
+                "{:>12} Skipping dangling symlink {}",
+                crate::putils::warn(),
+                path.display()
+            );
+            continue;
+        };
+        if meta.is_dir() {
+            if entry.file_type()?.is_symlink()
+                && src.canonicalize()?.starts_with(path.canonicalize()?)
+            {
+                continue;
+            }
+            copy_dir(&path, &dst.join(entry.file_name()))?;
+        } else {
+            copy(&path, dst.join(entry.file_name()))?;
+        }
     }
+    Ok(())
 }
 
 impl fmt::Display for Package {
@@ -483,6 +795,190 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn local() {
+        let t = crate::test_utils::mktemp().await;
+        let src = t.0.path().join("my-addon");
+        create_dir_all(&src).unwrap();
+        write(
+            src.join("package.json"),
+            r#"{"name":"my-addon","version":"0.1.0","dependencies":{"@bendn/gdcli":"1.2.5"}}"#,
+        )
+        .unwrap();
+        let main = "extends Node\nconst Cli = preload(\"res://addons/gdcli/cli.gd\")\n";
+        write(src.join("main.gd"), main).unwrap();
+        // a linked in directory, and a link back to the addon itself
+        let shared = t.0.path().join("shared");
+        create_dir_all(&shared).unwrap();
+        write(shared.join("util.gd"), "extends Object\n").unwrap();
+        crate::link::symlink_dir(&shared, &src.join("shared")).unwrap();
+        crate::link::symlink_dir(&src, &src.join("me")).unwrap();
+        let spec = format!("file:{}", src.display());
+        let mut p = Package::new("my-addon".into(), spec.clone(), t.2.clone())
+            .await
+            .unwrap();
+        assert_eq!(p.to_string(), "my-addon@0.1.0");
+        p.download(t.2.clone(), t.0.path()).await;
+        let dir = p.download_dir(t.0.path());
+        assert!(!dir.symlink_metadata().unwrap().is_symlink());
+        assert_eq!(read_to_string(dir.join("main.gd")).unwrap(), main);
+        assert!(!dir.join("shared").symlink_metadata().unwrap().is_symlink());
+        assert!(dir.join("shared/util.gd").exists());
+        assert!(!dir.join("me").exists());
+        p.modify(t.0.path());
+        let modified = read_to_string(dir.join("main.gd")).unwrap();
+        assert!(modified.contains("\"res://addons/__gpm_deps/@bendn/gdcli/1.2.5/cli.gd\")"));
+        assert_eq!(read_to_string(src.join("main.gd")).unwrap(), main);
+        p.link(t.0.path()).unwrap();
+        assert_eq!(dir.read_link().unwrap(), src.canonicalize().unwrap());
+        p.prepare_lock();
+        assert_eq!(p.manifest.tarball.to_string(), spec);
+    }
+
+    #[tokio::test]
+    async fn self_reference() {
+        let t = crate::test_utils::mktemp().await;
+        let (c, cwd) = (t.2, t.0.path());
+        let src = cwd.join("my-addon");
+        create_dir_all(&src).unwrap();
+        write(
+            src.join("package.json"),
+            r#"{"name":"my-addon","version":"0.1.0"}"#,
+        )
+        .unwrap();
+        let main = "extends Node\nconst Other = preload(\"res://addons/my-addon/other.gd\")\n";
+        write(src.join("main.gd"), main).unwrap();
+        write(src.join("other.gd"), "extends Node\n").unwrap();
+        let mut cfg = ConfigFile::new(
+            &format!(r#"packages: {{"my-addon": "file:{}"}}"#, src.display()),
+            c.clone(),
+        )
+        .await;
+        // the first install, so other.gd only exists in the staged tree
+        crate::update(
+            &mut cfg,
+            crate::UpdateOptions {
+                force: true,
+                ..Default::default()
+            },
+            crate::Verbosity::Quiet,
+            c.clone(),
+            cwd,
+        )
+        .await;
+        assert_eq!(
+            read_to_string(cwd.join("addons/my-addon/main.gd")).unwrap(),
+            main
+        );
+        // local copies get a install marker too
+        let lock = cfg.lock(cwd);
+        assert_eq!(crate::verify::verify(&mut cfg, Some(&lock), cwd), []);
+
+        // the copy is replaced by a link, after everything else is installed
+        let link = crate::UpdateOptions {
+            link: true,
+            ..Default::default()
+        };
+        crate::update(&mut cfg, link, crate::Verbosity::Quiet, c, cwd).await;
+        assert!(cfg.packages[0].is_linked(cwd));
+    }
+
+    #[tokio::test]
+    async fn asset() {
+        let t = crate::test_utils::mktemp().await;
+        let c = t.2;
+        // 1236 is a zip of just `addons/`, without a wrapping directory
+        for spec in ["assetlib:1234", "assetlib:splitter", "assetlib:1236"] {
+            let mut p = Package::new(spec.into(), "1.x".into(), c.clone())
+                .await
+                .unwrap();
+            assert_eq!(p.to_string(), "splitter@1.1.0");
+            assert_eq!(p.manifest.shasum.as_ref().map(String::len), Some(64));
+            p.download(c.clone(), t.0.path()).await;
+            let dir = t.0.path().join("addons/splitter");
+            assert!(dir.join("plugin.cfg").exists());
+            assert!(dir.join("splitter.gd").exists());
+            assert!(!dir.join("README.md").exists());
+        }
+        assert!(Package::new("assetlib:1234".into(), "2.x".into(), c)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn archive_root() {
+        let t = crate::test_utils::mktemp().await;
+        let c = t.2;
+        // the plugin gets found automatically
+        let mut p = Package::create_from_str("@bendn/plugin@1.0.0", c.clone())
+            .await
+            .unwrap();
+        p.download(c.clone(), t.0.path()).await;
+        let dir = p.download_dir(t.0.path());
+        assert!(dir.join("plugin.cfg").exists());
+        assert!(dir.join("package.json").exists());
+        assert!(!dir.join("README.md").exists());
+
+        // unless the path is specified
+        let cfg = crate::config_file::ConfigFile::new(
+            &r#"packages: {"@bendn/plugin": { version: "1.0.0", path: "demo" }}"#.into(),
+            c.clone(),
+        )
+        .await;
+        let mut p = cfg.packages[0].clone();
+        assert_eq!(p.root, Some("demo".into()));
+        p.download(c.clone(), t.0.path()).await;
+        assert!(dir.join("demo.gd").exists());
+        assert!(!dir.join("plugin.cfg").exists());
+        assert!(dir.join("package.json").exists());
+    }
+
+    #[tokio::test]
+    async fn filter() {
+        let t = crate::test_utils::mktemp().await;
+        let c = t.2;
+        let cfg = crate::config_file::ConfigFile::new(
+            &r#"packages: {"@bendn/filtered": { version: "1.0.0", exclude: ["lib/demo/**", "*.png.import"] }}"#.into(),
+            c.clone(),
+        )
+        .await;
+        let mut p = cfg.packages[0].clone();
+        p.download(c.clone(), t.0.path()).await;
+        let dir = p.download_dir(t.0.path());
+        let mut installed = glob::glob(&format!("{}/**/*", dir.display()))
+            .unwrap()
+            .map(|p| p.unwrap())
+            .filter(|p| p.is_file())
+            .map(|p| p.strip_prefix(&dir).unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        installed.sort();
+        assert_eq!(
+            installed,
+            [".gpm-meta.json", "README.md", "lib/main.gd", "package.json"]
+        );
+        assert!(p.is_up_to_date(t.0.path()));
+        p.exclude.clear();
+        assert!(!p.is_up_to_date(t.0.path()));
+    }
+
+    #[tokio::test]
+    async fn up_to_date_deps() {
+        let t = crate::test_utils::mktemp().await;
+        let (c, cwd) = (t.2, t.0.path());
+        let mut p = Package::create_from_str("@bendn/test=2.0.10", c.clone())
+            .await
+            .unwrap();
+        p.download(c, cwd).await;
+        assert!(p.is_up_to_date(cwd));
+        // the loads point to the old version of gdcli
+        let mut moved = p.clone();
+        moved.manifest.dependencies[0].manifest.version = Version::new("1.2.0").parse().unwrap();
+        assert!(!moved.is_up_to_date(cwd));
+        let mut moved = p.clone();
+        moved.manifest.dependencies[0].layout.deps = "deps".into();
+        assert!(!moved.is_up_to_date(cwd));
+    }
+
     #[tokio::test]
     async fn dep_map() {
         // no fs was touched in the making of this test
@@ -515,28 +1011,71 @@ mod tests {
         let mut p = Package::create_from_str("@bendn/test=2.0.10", c.clone())
             .await
             .unwrap();
-        let dep_map = &p.dep_map(t.0.path()).unwrap();
+        let dep_map = &p.dep_map(Path::new("")).unwrap();
         p.download(c, t.0.path()).await;
         p.indirect = false;
-        let cwd = t.0.path().join("addons/@bendn/test");
+        let roots = &[t.0.path().to_path_buf()];
+        let dir = t.0.path().join("addons/@bendn/test");
         assert_eq!(
-            Path::new(
-                p.modify_load(Path::new("addons/test/main.gd"), &cwd, dep_map)
-                    .to_str()
-                    .unwrap()
+            p.modify_load(Path::new("addons/test/main.gd"), &dir, roots, dep_map),
+            Path::new("addons/@bendn/test/main.gd")
+        );
+        // it works, so its kept
+        assert_eq!(
+            p.modify_load(
+                Path::new("addons/@bendn/test/main.gd"),
+                &dir,
+                roots,
+                dep_map
             ),
-            t.0.path().join("addons/@bendn/test/main.gd")
+            Path::new("addons/@bendn/test/main.gd")
         );
 
         // dependency usage test
         assert_eq!(
-            Path::new(
-                p.modify_load(Path::new("addons/gdcli/Parser.gd"), &cwd, dep_map)
-                    .to_str()
-                    .unwrap()
+            p.modify_load(Path::new("addons/gdcli/Parser.gd"), &dir, roots, dep_map),
+            Path::new("addons/__gpm_deps/@bendn/gdcli/1.2.5/Parser.gd")
+        );
+
+        // quotes are kept, comments are not touched
+        let path = |x: &str| p.modify_path(x, &dir, roots, dep_map);
+        assert_eq!(
+            rewrite::GDScript.rewrite(
+                "const P = preload(\"res://addons/gdcli/Parser.gd\") # load('res://addons/gdcli/x.gd')",
+                Path::new("main.gd"),
+                &path
             ),
+            "const P = preload(\"res://addons/__gpm_deps/@bendn/gdcli/1.2.5/Parser.gd\") # load('res://addons/gdcli/x.gd')"
+        );
+
+        // godot 4 headers, attributes in any order
+        let file =
             t.0.path()
-                .join("addons/__gpm_deps/@bendn/gdcli/1.2.5/Parser.gd")
-        )
+                .join("addons/__gpm_deps/@bendn/test/2.0.10/main.tscn");
+        let parser = "addons/__gpm_deps/@bendn/gdcli/1.2.5/Parser.gd";
+        let scene = rewrite::TextResource {
+            deps: "__gpm_deps".into(),
+        }
+        .rewrite(
+            r#"[gd_scene load_steps=2 format=3 uid="uid://b8lyn7k3w8ey2"]
+
+[ext_resource type="Script" uid="uid://c2ie5" path="res://addons/gdcli/Parser.gd" id="1_abc"]
+[ext_resource type="Texture2D" uid="uid://d3f" path="res://icon.png" id="2_def"]
+"#,
+            &file,
+            &path,
+        );
+        assert_eq!(
+            scene,
+            format!(
+                r#"[gd_scene load_steps=2 format=3 uid="{}"]
+
+[ext_resource type="Script" uid="{}" path="res://{parser}" id="1_abc"]
+[ext_resource type="Texture2D" uid="uid://d3f" path="res://icon.png" id="2_def"]
+"#,
+                crate::resource::uid_for(&file.to_string_lossy(), "__gpm_deps").unwrap(),
+                crate::resource::uid_for(parser, "__gpm_deps").unwrap(),
+            )
+        );
     }
 }
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Where packages get installed, set in the `layout` of a godot.package:
+/// ```yaml
+/// layout:
+///   root: addons # the install root
+///   deps: __gpm_deps # the folder for indirect dependencies (in the root)
+///   flatten: true # addons/bendn_test instead of addons/@bendn/test
+/// ```
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Layout {
+    pub root: PathBuf,
+    pub deps: String,
+    pub flatten: bool,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            root: "addons".into(),
+            deps: "__gpm_deps".into(),
+            flatten: false,
+        }
+    }
+}
+
+impl Layout {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The directory name of a package, `@bendn/test` (or `bendn_test` when flattening).
+    pub fn dir_name(&self, name: &str) -> PathBuf {
+        if self.flatten {
+            name.trim_start_matches('@').replace('/', "_").into()
+        } else {
+            name.into()
+        }
+    }
+
+    /// Where direct dependencies go, `addons/<name>`.
+    pub fn direct(&self, cwd: &Path, name: &str) -> PathBuf {
+        cwd.join(&self.root).join(self.dir_name(name))
+    }
+
+    /// Where indirect dependencies go, `addons/__gpm_deps/<name>/<version>`.
+    pub fn indirect(&self, cwd: &Path, name: &str, version: &str) -> PathBuf {
+        self.deps_dir(cwd).join(self.dir_name(name)).join(version)
+    }
+
+    /// The folder of the indirect dependencies, `addons/__gpm_deps`.
+    pub fn deps_dir(&self, cwd: &Path) -> PathBuf {
+        cwd.join(&self.root).join(&self.deps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::package::layout::*;
+
+    #[test]
+    fn layout() {
+        let l = Layout::default();
+        assert_eq!(
+            l.direct(Path::new(""), "@bendn/test"),
+            Path::new("addons/@bendn/test")
+        );
+        assert_eq!(
+            l.indirect(Path::new(""), "@bendn/gdcli", "1.2.5"),
+            Path::new("addons/__gpm_deps/@bendn/gdcli/1.2.5")
+        );
+        let l = Layout {
+            root: "lib".into(),
+            deps: "deps".into(),
+            flatten: true,
+        };
+        assert_eq!(
+            l.direct(Path::new(""), "@bendn/test"),
+            Path::new("lib/bendn_test")
+        );
+        assert_eq!(
+            l.indirect(Path::new(""), "@bendn/gdcli", "1.2.5"),
+            Path::new("lib/deps/bendn_gdcli/1.2.5")
+        );
+    }
+}
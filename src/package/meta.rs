@@ -0,0 +1,171 @@
+use crate::package::Package;
+
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::BTreeMap;
+use std::fs::{read, read_dir, read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The install marker, written to every installed package.
+pub const META_FILE: &str = ".gpm-meta.json";
+
+/// What was installed, so `update` can skip packages that did not change,
+/// and notice files that were edited after installing.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct Meta {
+    #[serde(flatten)]
+    pub origin: Origin,
+    /// The sha1 of every installed file, relative to the package.
+    #[serde(default)]
+    pub files: BTreeMap<PathBuf, String>,
+}
+
+/// Where a installed package came from.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct Origin {
+    pub version: String,
+    /// The shasum of the tarball, or its uri if there is none (git uris are pinned to a commit).
+    pub integrity: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// The sha1 of the applied patch, see [crate::patch].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patch: Option<String>,
+    /// Where the dependencies were installed (see [Package::dep_map]), as the loads were rewritten to point there.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub deps: BTreeMap<String, PathBuf>,
+}
+
+impl Origin {
+    pub fn of(p: &Package, cwd: &Path) -> Self {
+        Self {
+            version: p.manifest.version.to_string(),
+            integrity: p
+                .manifest
+                .shasum
+                .clone()
+                .unwrap_or_else(|| p.manifest.tarball.to_string()),
+            root: p.root.clone(),
+            exclude: p.exclude.clone(),
+            patch: crate::patch::hash(p, cwd),
+            deps: p
+                .dep_map(Path::new(""))
+                .expect("Should be able to map dependencies")
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl Meta {
+    /// The marker for `p`, installed in `dir`.
+    pub fn of(p: &Package, dir: &Path, cwd: &Path) -> io::Result<Self> {
+        Ok(Self {
+            origin: Origin::of(p, cwd),
+            files: hash_files(dir)?,
+        })
+    }
+
+    /// Reads the marker of the package installed in `dir`, if there is one.
+    pub fn read(dir: &Path) -> Option<Self> {
+        serde_json::from_str(&read_to_string(dir.join(META_FILE)).ok()?).ok()
+    }
+
+    pub fn write(&self, dir: &Path) -> io::Result<()> {
+        write(
+            dir.join(META_FILE),
+            serde_json::to_string_pretty(self).unwrap(),
+        )
+    }
+
+    /// The files in `dir` that were changed, added or deleted since installing (relative to `dir`), sorted.
+    pub fn modified(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let now = hash_files(dir)?;
+        let mut modified = now
+            .iter()
+            .filter(|(p, hash)| self.files.get(*p) != Some(hash))
+            .chain(self.files.iter().filter(|(p, _)| !now.contains_key(*p)))
+            .map(|(p, _)| p.clone())
+            .collect::<Vec<_>>();
+        modified.sort();
+        Ok(modified)
+    }
+}
+
+/// The files of the package installed in `dir` that were modified since installing (see [Meta::modified]), relative to `cwd`.
+/// Directories without a install marker are assumed to be unmodified.
+pub fn modified_files(dir: &Path, cwd: &Path) -> Vec<PathBuf> {
+    let Some(m) = Meta::read(dir) else {
+        return vec![];
+    };
+    let rel = dir.strip_prefix(cwd).unwrap_or(dir);
+    m.modified(dir)
+        .expect("Should be able to read installed package")
+        .into_iter()
+        .map(|f| rel.join(f))
+        .collect()
+}
+
+/// Hashes every file in `dir`, except the marker.
+fn hash_files(dir: &Path) -> io::Result<BTreeMap<PathBuf, String>> {
+    fn walk(dir: &Path, rel: &Path, out: &mut BTreeMap<PathBuf, String>) -> io::Result<()> {
+        for entry in read_dir(dir)? {
+            let entry = entry?;
+            let rel = rel.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                walk(&entry.path(), &rel, out)?;
+            } else if rel != Path::new(META_FILE) {
+                out.insert(rel, format!("{:x}", Sha1::digest(read(entry.path())?)));
+            }
+        }
+        Ok(())
+    }
+    let mut files = BTreeMap::new();
+    walk(dir, Path::new(""), &mut files)?;
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::package::meta::*;
+    use std::fs::create_dir;
+    use tempfile::TempDir;
+
+    #[test]
+    fn modified() {
+        let t = TempDir::new().unwrap();
+        let dir = t.path();
+        create_dir(dir.join("sub")).unwrap();
+        write(dir.join("main.gd"), "extends Node\n").unwrap();
+        write(dir.join("sub/util.gd"), "extends Object\n").unwrap();
+        let meta = Meta {
+            origin: Origin {
+                version: "1.0.0".into(),
+                integrity: "d7c44b0e3b0d236acc2b773d9511a75d5996afa9".into(),
+                root: None,
+                exclude: vec![],
+                patch: None,
+                deps: BTreeMap::new(),
+            },
+            files: hash_files(dir).unwrap(),
+        };
+        meta.write(dir).unwrap();
+        assert_eq!(Meta::read(dir).unwrap(), meta);
+        assert!(meta.modified(dir).unwrap().is_empty());
+
+        write(dir.join("sub/util.gd"), "extends RefCounted\n").unwrap();
+        write(dir.join("new.gd"), "").unwrap();
+        std::fs::remove_file(dir.join("main.gd")).unwrap();
+        assert_eq!(
+            meta.modified(dir).unwrap(),
+            [
+                PathBuf::from("main.gd"),
+                PathBuf::from("new.gd"),
+                PathBuf::from("sub/util.gd")
+            ]
+        );
+    }
+}
@@ -7,7 +7,9 @@ use async_trait::async_trait;
 use futures::stream::{self, StreamExt};
 use semver_rs::Version;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::PathBuf;
 
 #[derive(Clone, Debug, Default)]
 pub struct ParsedPackage {
@@ -81,7 +83,10 @@ impl std::str::FromStr for ParsedPackage {
         fn split_p(s: &str, d: char) -> Result<ParsedPackage> {
             let Some((p, v)) = s.split_once(d) else {
                 check(s)?;
-                return Ok(ParsedPackage {name: s.to_string(), version: VersionType::Latest });
+                return Ok(ParsedPackage {
+                    name: s.to_string(),
+                    version: VersionType::Latest,
+                });
             };
             check(p)?;
             Ok(ParsedPackage {
@@ -94,7 +99,7 @@ impl std::str::FromStr for ParsedPackage {
             split_p(s, ':')
         } else if s.contains('=') {
             // @bendn/gdcli=1.2.5
-            return split_p(s, '=');
+            split_p(s, '=')
         } else {
             // @bendn/gdcli@1.2.5
             if s.as_bytes()[0] == b'@' {
@@ -102,7 +107,10 @@ impl std::str::FromStr for ParsedPackage {
                 owned_s.remove(0);
                 let Some((p, v)) = owned_s.split_once('@') else {
                     check(s)?;
-                    return Ok(ParsedPackage {name: s.to_string(), version: VersionType::Latest });
+                    return Ok(ParsedPackage {
+                        name: s.to_string(),
+                        version: VersionType::Latest,
+                    });
                 };
                 check(&format!("@{p}")[..])?;
                 return Ok(ParsedPackage {
@@ -110,17 +118,88 @@ impl std::str::FromStr for ParsedPackage {
                     version: VersionType::Normal(v.to_string()),
                 });
             }
-            return split_p(s, '@');
+            split_p(s, '@')
         }
     }
 }
 
+/// A dependency in a godot.package.
+/// Either just the version, `"@bendn/test": "2.0.10"`,
+/// or a table with install options, `"@bendn/test": { version: "2.0.10", path: "addons/test" }`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ParsedDependency {
+    Version(String),
+    Detailed(DetailedDependency),
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DetailedDependency {
+    #[serde(default)]
+    pub version: String,
+    /// The subdirectory of the archive that becomes the package root.
+    #[serde(alias = "root", skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Globs of files to not install, like `["demo/**", "*.png.import"]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Enable the editor plugins (`plugin.cfg`s) of this package in project.godot.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub plugin: bool,
+}
+
+impl ParsedDependency {
+    pub fn version(&self) -> &str {
+        match self {
+            ParsedDependency::Version(v) => v,
+            ParsedDependency::Detailed(d) => &d.version,
+        }
+    }
+
+    /// Applies the install options to a [Package].
+    pub fn apply(&self, p: &mut Package) {
+        if let ParsedDependency::Detailed(d) = self {
+            if d.path.is_some() {
+                p.root = d.path.clone();
+            }
+            p.exclude = d.exclude.clone();
+            p.plugin = d.plugin;
+        }
+    }
+}
+
+/// The `godot` section of a package.json, like `"godot": {"autoloads": {"Console": "console.gd"}}`.
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GodotSection {
+    /// Singletons to register in project.godot: name => path (relative to the package).
+    pub autoloads: BTreeMap<String, String>,
+    /// The godot versions the package supports, like `>=4.1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engine: Option<String>,
+}
+
+impl GodotSection {
+    pub fn is_empty(&self) -> bool {
+        self.autoloads.is_empty() && self.engine.is_none()
+    }
+}
+
+/// The parts of a installed package.json that gpm uses.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct InstalledManifest {
+    pub godot: GodotSection,
+}
+
 #[derive(Clone, Default, Deserialize, Serialize)]
 pub struct ParsedManifest {
     pub dist: ParsedManifestDist,
     #[serde(default)]
     pub dependencies: HashMap<String, String>,
     pub version: String,
+    #[serde(default)]
+    pub godot: GodotSection,
 }
 
 impl fmt::Debug for ParsedManifest {
@@ -143,6 +222,7 @@ impl TryFromAsync<ParsedManifest> for Manifest {
             tarball: CompressionType::Gzip(Data::new_uri(value.dist.tarball)),
             version: Version::new(&value.version).parse()?,
             dependencies: value.dependencies.try_into_async(client).await?,
+            engine: value.godot.engine,
         })
     }
 }
@@ -177,7 +257,7 @@ impl TryFromAsync<HashMap<String, String>> for Vec<Package> {
         value: HashMap<String, String>,
         client: Client,
     ) -> Result<Vec<Package>> {
-        stream::iter(value.into_iter())
+        stream::iter(value)
             .map(|(name, version)| async {
                 let client = client.clone();
                 async move {
@@ -197,10 +277,34 @@ impl TryFromAsync<HashMap<String, String>> for Vec<Package> {
     }
 }
 
+#[async_trait]
+impl TryFromAsync<HashMap<String, ParsedDependency>> for Vec<Package> {
+    async fn try_from_async(
+        value: HashMap<String, ParsedDependency>,
+        client: Client,
+    ) -> Result<Vec<Package>> {
+        stream::iter(value)
+            .map(|(name, dep)| async {
+                let client = client.clone();
+                async move {
+                    let mut p = Package::new(name, dep.version().to_string(), client).await?;
+                    dep.apply(&mut p);
+                    Ok(p)
+                }
+                .await
+            })
+            .buffer_unordered(crate::PARALLEL)
+            .collect::<Vec<Result<Package>>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+}
+
 #[async_trait]
 impl TryFromAsync<Vec<ParsedPackage>> for Vec<Package> {
     async fn try_from_async(value: Vec<ParsedPackage>, client: Client) -> Result<Vec<Package>> {
-        stream::iter(value.into_iter())
+        stream::iter(value)
             .map(|pp| async {
                 let client = client.clone();
                 async move { pp.into_package(client).await }.await
@@ -0,0 +1,405 @@
+use crate::package::layout::Layout;
+use crate::resource::{tags, uid_for};
+
+use std::ops::Range;
+use std::path::Path;
+
+/// Rewrites the `res://` paths in one kind of file, see [rewriters].
+/// `path` maps a path to where it should point now (or returns it as is).
+pub trait Rewriter {
+    /// Wether this rewriter handles `file`.
+    fn handles(&self, file: &Path) -> bool;
+    fn rewrite(&self, text: &str, file: &Path, path: &dyn Fn(&str) -> String) -> String;
+}
+
+/// The rewriters used when modifying a package installed with `layout`. The first one that handles a file is used.
+pub fn rewriters(layout: &Layout) -> Vec<Box<dyn Rewriter>> {
+    vec![
+        Box::new(GDScript),
+        Box::new(TextResource {
+            deps: layout.deps.clone(),
+        }),
+        Box::new(Uid {
+            deps: layout.deps.clone(),
+        }),
+        Box::new(Import {
+            deps: layout.deps.clone(),
+        }),
+        Box::new(Shader),
+        Box::new(ResStrings),
+    ]
+}
+
+fn has_extension(file: &Path, extensions: &[&str]) -> bool {
+    file.extension()
+        .is_some_and(|e| extensions.iter().any(|x| e == *x))
+}
+
+/// Replaces the `ranges` of `text` with `path(range)`.
+fn replace_paths(
+    text: &str,
+    ranges: impl IntoIterator<Item = Range<usize>>,
+    path: &dyn Fn(&str) -> String,
+) -> String {
+    crate::resource::replace(
+        text,
+        ranges
+            .into_iter()
+            .map(|r| {
+                let new = path(&text[r.clone()]);
+                (r, new)
+            })
+            .collect(),
+    )
+}
+
+/// The loads of a GDScript script.
+/// ```gdscript
+/// const Wow = preload("res://addons/my_awesome_addon/wow.gd")
+/// ```
+/// =>
+/// ```gdscript
+/// const Wow = preload("res://addons/__gpm_deps/my_awesome_addon/wow.gd")
+/// ```
+pub struct GDScript;
+
+impl Rewriter for GDScript {
+    fn handles(&self, file: &Path) -> bool {
+        has_extension(file, &["gd", "gdscript"])
+    }
+
+    fn rewrite(&self, text: &str, _: &Path, path: &dyn Fn(&str) -> String) -> String {
+        replace_paths(text, crate::gdscript::load_paths(text), path)
+    }
+}
+
+/// The loads of a godot TextResource.
+/// ```gdresource
+/// [gd_scene load_steps=1 format=3 uid="uid://b8lyn7k3w8ey2"]
+///
+/// [ext_resource type="Script" uid="uid://c2ie5" path="res://addons/my_awesome_addon/wow.gd" id="1_abc"]
+/// ```
+/// =>
+/// ```gdresource
+/// --snip--
+/// [ext_resource type="Script" uid="uid://dr1g4y" path="res://addons/__gpm_deps/my_awesome_addon/wow.gd" id="1_abc"]
+/// ```
+/// Resources in `__gpm_deps` get new uids (see [uid_for]), in their header and in the references to them.
+/// References to binary resources keep their uid, as that is stored inside the resource.
+pub struct TextResource {
+    /// The name of the folder of the indirect dependencies.
+    pub deps: String,
+}
+
+impl Rewriter for TextResource {
+    fn handles(&self, file: &Path) -> bool {
+        has_extension(file, &["tres", "tscn"])
+    }
+
+    fn rewrite(&self, text: &str, file: &Path, path: &dyn Fn(&str) -> String) -> String {
+        let mut edits = vec![];
+        for tag in tags(text) {
+            let uid = tag.attr("uid").map(|a| a.value.clone());
+            match tag.name.as_str() {
+                "ext_resource" => {
+                    let Some(p) = tag.attr("path") else {
+                        continue;
+                    };
+                    let old = &text[p.value.clone()];
+                    let new = path(old);
+                    if new == old {
+                        continue;
+                    }
+                    if let (Some(uid), Some(new_uid), true) =
+                        (uid, uid_for(&new, &self.deps), regenerated(&new))
+                    {
+                        edits.push((uid, new_uid));
+                    }
+                    edits.push((p.value.clone(), new));
+                }
+                "gd_scene" | "gd_resource" => {
+                    if let (Some(uid), Some(new_uid)) =
+                        (uid, uid_for(&file.to_string_lossy(), &self.deps))
+                    {
+                        edits.push((uid, new_uid));
+                    }
+                }
+                _ => {}
+            }
+        }
+        crate::resource::replace(text, edits)
+    }
+}
+
+/// `x.gd.uid`, the uid of `x.gd` (godot 4.4+). Regenerated like the uids of [TextResource]s.
+pub struct Uid {
+    pub deps: String,
+}
+
+impl Rewriter for Uid {
+    fn handles(&self, file: &Path) -> bool {
+        has_extension(file, &["uid"])
+    }
+
+    fn rewrite(&self, text: &str, file: &Path, _: &dyn Fn(&str) -> String) -> String {
+        match uid_for(&file.with_extension("").to_string_lossy(), &self.deps) {
+            Some(uid) => format!("{uid}\n"),
+            None => text.to_string(),
+        }
+    }
+}
+
+/// `icon.png.import`, the import settings of `icon.png`. Its `uid=` is regenerated like the uids of [TextResource]s,
+/// and its `source_file` is rewritten like in [ResStrings].
+pub struct Import {
+    pub deps: String,
+}
+
+impl Rewriter for Import {
+    fn handles(&self, file: &Path) -> bool {
+        has_extension(file, &["import"])
+    }
+
+    fn rewrite(&self, text: &str, file: &Path, path: &dyn Fn(&str) -> String) -> String {
+        let Some(uid) = uid_for(&file.with_extension("").to_string_lossy(), &self.deps) else {
+            return replace_paths(text, res_strings(text), path);
+        };
+        let mut edits = lines(text)
+            .filter_map(|(start, line)| {
+                let (key, value) = line.split_once('=')?;
+                if key.trim() != "uid" {
+                    return None;
+                }
+                let r = quoted(value)?;
+                let offset = start + key.len() + 1;
+                Some((offset + r.start..offset + r.end, uid.clone()))
+            })
+            .collect::<Vec<_>>();
+        edits.extend(res_strings(text).map(|r| {
+            let new = path(&text[r.clone()]);
+            (r, new)
+        }));
+        crate::resource::replace(text, edits)
+    }
+}
+
+/// Wether the uid of the resource at `path` gets regenerated: text resources, imported files and scripts
+/// keep their uid in a file gpm rewrites ([TextResource], [Import], [Uid]). Binary resources do not.
+fn regenerated(path: &str) -> bool {
+    !has_extension(Path::new(path), &["res", "scn"])
+}
+
+/// The `#include`s of a shader, if they are `res://` paths (relative ones always work).
+/// ```glsl
+/// #include "res://addons/my_awesome_addon/noise.gdshaderinc"
+/// ```
+pub struct Shader;
+
+impl Rewriter for Shader {
+    fn handles(&self, file: &Path) -> bool {
+        has_extension(file, &["gdshader", "gdshaderinc"])
+    }
+
+    fn rewrite(&self, text: &str, _: &Path, path: &dyn Fn(&str) -> String) -> String {
+        let ranges = lines(text).filter_map(|(start, line)| {
+            let trimmed = line.trim_start();
+            let rest = trimmed.strip_prefix("#include")?;
+            let r = quoted(rest)?;
+            let offset = start + (line.len() - rest.len());
+            Some(offset + r.start..offset + r.end)
+        });
+        replace_paths(text, res_only(text, ranges), path)
+    }
+}
+
+/// Every `"res://"` string of a config (`.cfg`, like a editor plugins `plugin.cfg`) or json file, as these have no fixed place for paths.
+/// ```ini
+/// [plugin]
+/// script="res://addons/my_awesome_addon/plugin.gd"
+/// icon="res://addons/my_awesome_addon/icon.svg"
+/// ```
+pub struct ResStrings;
+
+impl Rewriter for ResStrings {
+    fn handles(&self, file: &Path) -> bool {
+        has_extension(file, &["cfg", "json"])
+    }
+
+    fn rewrite(&self, text: &str, _: &Path, path: &dyn Fn(&str) -> String) -> String {
+        replace_paths(text, res_strings(text), path)
+    }
+}
+
+/// The ranges of the contents of the `"res://"` strings in `text`.
+/// The import cache (`res://.godot/`) is skipped, godot makes that on its own.
+fn res_strings(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let b = text.as_bytes();
+    let mut ranges = vec![];
+    let mut i = 0;
+    while i < b.len() {
+        if b[i] != b'"' {
+            i += 1;
+            continue;
+        }
+        let start = i + 1;
+        i = start;
+        while i < b.len() && b[i] != b'"' {
+            i += if b[i] == b'\\' { 2 } else { 1 };
+        }
+        ranges.push(start..i.min(b.len()));
+        i += 1;
+    }
+    ranges.into_iter().filter(|r| {
+        let s = &text[r.clone()];
+        s.starts_with("res://") && !s.starts_with("res://.godot/")
+    })
+}
+
+/// The lines of `text`, with their starting index.
+fn lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_inclusive('\n').scan(0, |start, line| {
+        let s = *start;
+        *start += line.len();
+        Some((s, line.trim_end_matches(['\r', '\n'])))
+    })
+}
+
+/// The range (in `s`) of the contents of the first `"string"` in `s`, if `s` only has whitespace before it.
+fn quoted(s: &str) -> Option<Range<usize>> {
+    let open = s.len() - s.trim_start().len();
+    if s.as_bytes().get(open) != Some(&b'"') {
+        return None;
+    }
+    let close = s[open + 1..].find('"')? + open + 1;
+    Some(open + 1..close)
+}
+
+fn res_only<'a>(
+    text: &'a str,
+    ranges: impl Iterator<Item = Range<usize>> + 'a,
+) -> impl Iterator<Item = Range<usize>> + 'a {
+    ranges.filter(|r| text[r.clone()].starts_with("res://"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::package::rewrite::*;
+
+    fn moved(p: &str) -> String {
+        p.replace(
+            "res://addons/noise/",
+            "res://addons/__gpm_deps/noise/1.0.0/",
+        )
+    }
+
+    #[test]
+    fn plugin_cfg() {
+        let cfg = "[plugin]\n\nname=\"x\"\nscript = \"res://addons/noise/plugin.gd\"\r\nicon=\"res://addons/noise/icon.png\"\n";
+        assert_eq!(
+            ResStrings.rewrite(cfg, Path::new("plugin.cfg"), &moved),
+            "[plugin]\n\nname=\"x\"\nscript = \"res://addons/__gpm_deps/noise/1.0.0/plugin.gd\"\r\nicon=\"res://addons/__gpm_deps/noise/1.0.0/icon.png\"\n"
+        );
+        assert_eq!(
+            ResStrings.rewrite(
+                "[plugin]\nscript=\"plugin.gd\"",
+                Path::new("plugin.cfg"),
+                &moved
+            ),
+            "[plugin]\nscript=\"plugin.gd\""
+        );
+    }
+
+    #[test]
+    fn shader() {
+        let shader = "shader_type canvas_item;\n  #include \"res://addons/noise/noise.gdshaderinc\"\n#include \"local.gdshaderinc\"\n// #include \"res://addons/noise/x.gdshaderinc\"\n";
+        assert_eq!(
+            Shader.rewrite(shader, Path::new("x.gdshader"), &moved),
+            "shader_type canvas_item;\n  #include \"res://addons/__gpm_deps/noise/1.0.0/noise.gdshaderinc\"\n#include \"local.gdshaderinc\"\n// #include \"res://addons/noise/x.gdshaderinc\"\n"
+        );
+    }
+
+    #[test]
+    fn imported() {
+        let uid = |p: &str| uid_for(p, "__gpm_deps").unwrap();
+        let scene = TextResource {
+            deps: "__gpm_deps".into(),
+        }
+        .rewrite(
+            r#"[gd_scene load_steps=3 format=3 uid="uid://b8lyn7k3w8ey2"]
+
+[ext_resource type="Texture2D" uid="uid://cvx1" path="res://addons/noise/icon.png" id="1_abc"]
+[ext_resource type="Material" uid="uid://d3f" path="res://addons/noise/mat.res" id="2_def"]
+"#,
+            Path::new("addons/__gpm_deps/x/1.0.0/main.tscn"),
+            &moved,
+        );
+        assert_eq!(
+            scene,
+            format!(
+                r#"[gd_scene load_steps=3 format=3 uid="{}"]
+
+[ext_resource type="Texture2D" uid="{}" path="res://addons/__gpm_deps/noise/1.0.0/icon.png" id="1_abc"]
+[ext_resource type="Material" uid="uid://d3f" path="res://addons/__gpm_deps/noise/1.0.0/mat.res" id="2_def"]
+"#,
+                uid("addons/__gpm_deps/x/1.0.0/main.tscn"),
+                uid("res://addons/__gpm_deps/noise/1.0.0/icon.png"),
+            )
+        );
+        // the png gets the same uid the scene now points to
+        let import = Import {
+            deps: "__gpm_deps".into(),
+        };
+        assert_eq!(
+            import.rewrite(
+                "[remap]\n\nimporter=\"texture\"\nuid=\"uid://cvx1\"\n",
+                Path::new("/tmp/project/addons/__gpm_deps/noise/1.0.0/icon.png.import"),
+                &moved
+            ),
+            format!(
+                "[remap]\n\nimporter=\"texture\"\nuid=\"{}\"\n",
+                uid("res://addons/__gpm_deps/noise/1.0.0/icon.png")
+            )
+        );
+        let direct = "[remap]\nuid=\"uid://cvx1\"\n";
+        assert_eq!(
+            import.rewrite(direct, Path::new("addons/noise/icon.png.import"), &moved),
+            direct
+        );
+    }
+
+    #[test]
+    fn strings() {
+        let json = r#"{"levels": ["res://addons/noise/a.tscn", "res://icon.png"], "name": "res", "x": "\"res://addons/noise/no\""}"#;
+        assert_eq!(
+            ResStrings.rewrite(json, Path::new("levels.json"), &moved),
+            r#"{"levels": ["res://addons/__gpm_deps/noise/1.0.0/a.tscn", "res://icon.png"], "name": "res", "x": "\"res://addons/noise/no\""}"#
+        );
+        let import = "[remap]\npath=\"res://.godot/imported/icon.png-1.ctex\"\n[deps]\nsource_file=\"res://addons/noise/icon.png\"\ndest_files=[\"res://.godot/imported/icon.png-1.ctex\"]\n";
+        assert_eq!(
+            Import {
+                deps: "__gpm_deps".into()
+            }
+            .rewrite(import, Path::new("addons/noise/icon.png.import"), &moved),
+            import.replace(
+                "source_file=\"res://addons/noise/",
+                "source_file=\"res://addons/__gpm_deps/noise/1.0.0/"
+            )
+        );
+    }
+
+    #[test]
+    fn dispatch() {
+        let kind = |f: &str| {
+            rewriters(&Layout::default())
+                .iter()
+                .position(|r| r.handles(Path::new(f)))
+        };
+        assert_eq!(kind("a/main.gd"), Some(0));
+        assert_eq!(kind("a/main.tscn"), Some(1));
+        assert_eq!(kind("a/icon.png.import"), Some(3));
+        assert_eq!(kind("a/x.gdshaderinc"), Some(4));
+        assert_eq!(kind("a/plugin.cfg"), Some(5));
+        assert_eq!(kind("a/levels.json"), Some(5));
+        assert_eq!(kind("a/README.md"), None);
+    }
+}
@@ -0,0 +1,272 @@
+use crate::package::meta::{Meta, META_FILE};
+use crate::package::Package;
+use crate::transaction::Transaction;
+use crate::{ctx, putils, Client};
+
+use anyhow::{anyhow, Context, Result};
+use diffy::{DiffOptions, Patch};
+use sha1::{Digest, Sha1};
+use std::collections::BTreeSet;
+use std::fs::{create_dir_all, read, read_dir, remove_file, write};
+use std::path::{Path, PathBuf};
+
+const DEV_NULL: &str = "/dev/null";
+
+/// Where the patch for this exact version of `p` lives: `patches/<name>@<version>.patch`,
+/// with the `/` of scoped names replaced by `+` (`@bendn/test` => `@bendn+test`).
+pub fn path(p: &Package, cwd: &Path) -> PathBuf {
+    cwd.join("patches").join(format!(
+        "{}@{}.patch",
+        p.name.replace('/', "+"),
+        p.manifest.version
+    ))
+}
+
+/// The sha1 of the patch for `p`, if there is one.
+pub fn hash(p: &Package, cwd: &Path) -> Option<String> {
+    read(path(p, cwd))
+        .ok()
+        .map(|b| format!("{:x}", Sha1::digest(b)))
+}
+
+/// Applies the patch for `p` (if there is one) to the package in `dir`.
+pub fn apply_for(p: &Package, dir: &Path, cwd: &Path) -> Result<()> {
+    let patch = path(p, cwd);
+    let Ok(text) = std::fs::read_to_string(&patch) else {
+        return Ok(());
+    };
+    ctx!(
+        apply(&text, dir),
+        "{} no longer applies to {p} (run `gpm patch {}` to redo it)",
+        patch.display(),
+        p.name
+    )
+}
+
+/// Creates a (multi file) unified diff from `clean` to `edited`. Changed binary files are skipped.
+pub fn diff(clean: &Path, edited: &Path) -> Result<String> {
+    let mut files = BTreeSet::new();
+    list(clean, Path::new(""), &mut files)?;
+    list(edited, Path::new(""), &mut files)?;
+    let mut out = String::new();
+    for f in files {
+        if read(clean.join(&f)).ok() == read(edited.join(&f)).ok() {
+            continue;
+        }
+        let (Some(old), Some(new)) = (text(&clean.join(&f)), text(&edited.join(&f))) else {
+            eprintln!(
+                "{:>12} skipping binary file {}",
+                putils::warn(),
+                f.display()
+            );
+            continue;
+        };
+        let name = f.to_string_lossy().replace('\\', "/");
+        let exists = |p: &Path| p.join(&f).exists();
+        let patch = DiffOptions::new()
+            .set_original_filename(if exists(clean) {
+                format!("a/{name}")
+            } else {
+                DEV_NULL.into()
+            })
+            .set_modified_filename(if exists(edited) {
+                format!("b/{name}")
+            } else {
+                DEV_NULL.into()
+            })
+            .create_patch(&old, &new)
+            .to_string();
+        out.push_str(&format!("diff --git a/{name} b/{name}\n{patch}"));
+    }
+    Ok(out)
+}
+
+/// Applies a patch made by [diff] to `dir`.
+pub fn apply(patch: &str, dir: &Path) -> Result<()> {
+    for file in patch.split("diff --git ").skip(1) {
+        let body = file.split_once('\n').map_or("", |(_, b)| b);
+        let parsed = Patch::from_str(body).map_err(|e| anyhow!("{e}"))?;
+        let name = |n: Option<&str>| {
+            n.filter(|n| *n != DEV_NULL)
+                .map(|n| n.split_once('/').map_or(n, |(_, n)| n).to_string())
+        };
+        let (old, new) = (name(parsed.original()), name(parsed.modified()));
+        let Some(rel) = new.clone().or(old) else {
+            continue;
+        };
+        let target = dir.join(&rel);
+        let base = match target.exists() {
+            true => String::from_utf8(read(&target)?)?,
+            false => String::new(),
+        };
+        let patched = diffy::apply(&base, &parsed).map_err(|e| anyhow!("{rel}: {e}"))?;
+        if new.is_none() {
+            remove_file(&target)?;
+        } else {
+            create_dir_all(target.parent().unwrap())?;
+            write(&target, patched)?;
+        }
+    }
+    Ok(())
+}
+
+/// Stages a fresh copy of `p`: downloaded, modified, and (if `patched`) patched.
+async fn stage_fresh(
+    p: &mut Package,
+    client: Client,
+    tx: &Transaction,
+    cwd: &Path,
+    patched: bool,
+) -> Result<PathBuf> {
+    let rel = p.stage(client, tx, |_, _| {}).await;
+    p.modify_staged(tx, cwd);
+    if patched {
+        apply_for(p, &tx.staged(&rel), cwd)?;
+    }
+    Ok(rel)
+}
+
+/// Reinstalls `p` (with its current patch), for editing.
+/// Unless `force`, asks what to do with edits that are not in the patch yet (see [crate::protect_modified]).
+pub async fn edit(p: &mut Package, client: Client, force: bool, cwd: &Path) -> Result<PathBuf> {
+    if p.is_linked(cwd) {
+        return Err(anyhow!("{p} is linked, edit its source instead"));
+    }
+    let tx = Transaction::new(cwd)?;
+    let rel = stage_fresh(p, client, &tx, cwd, true).await?;
+    let modified = if force { vec![] } else { p.modified_files(cwd) };
+    if !modified.is_empty() {
+        crate::protect_modified(&modified, cwd);
+    }
+    p.mark_staged(&tx, cwd);
+    tx.commit([rel])?;
+    Ok(p.download_dir(cwd))
+}
+
+/// Saves the edits made to the installed `p` as its patch.
+/// Returns the patch file, or [None] if there were no edits (removing the patch).
+pub async fn commit(p: &mut Package, client: Client, cwd: &Path) -> Result<Option<PathBuf>> {
+    let installed = p.download_dir(cwd);
+    if !installed.exists() || p.is_linked(cwd) {
+        return Err(anyhow!("{p} is not installed (or is linked)"));
+    }
+    let tx = Transaction::new(cwd)?; // never committed, only used for the clean copy
+    let rel = stage_fresh(p, client, &tx, cwd, false).await?;
+    let patch = diff(&tx.staged(&rel), &installed)?;
+    let file = path(p, cwd);
+    let saved = if patch.is_empty() {
+        if file.exists() {
+            remove_file(&file)?;
+        }
+        None
+    } else {
+        create_dir_all(file.parent().unwrap())?;
+        write(&file, patch)?;
+        Some(file)
+    };
+    // the edits are not local modifications anymore
+    ctx!(
+        Meta::of(p, &installed, cwd).and_then(|m| m.write(&installed)),
+        "updating install marker of {p}"
+    )?;
+    Ok(saved)
+}
+
+/// Lists the files in `dir` (relative to it), skipping the install marker.
+fn list(dir: &Path, rel: &Path, out: &mut BTreeSet<PathBuf>) -> Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in read_dir(dir)? {
+        let entry = entry?;
+        let rel = rel.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            list(&entry.path(), &rel, out)?;
+        } else if rel != Path::new(META_FILE) {
+            out.insert(rel);
+        }
+    }
+    Ok(())
+}
+
+/// Reads a file as text, with missing files being empty. [None] if its not text.
+fn text(p: &Path) -> Option<String> {
+    match read(p) {
+        Ok(b) => String::from_utf8(b).ok(),
+        Err(_) => Some(String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::patch::*;
+    use std::fs::read_to_string;
+    use tempfile::TempDir;
+
+    #[test]
+    fn roundtrip() {
+        let t = TempDir::new().unwrap();
+        let (clean, edited) = (t.path().join("clean"), t.path().join("edited"));
+        for d in [&clean, &edited] {
+            create_dir_all(d.join("sub")).unwrap();
+            write(
+                d.join("main.gd"),
+                "extends Node\n\nfunc _ready():\n\tpass\n",
+            )
+            .unwrap();
+        }
+        write(clean.join("sub/gone.gd"), "extends Object\n").unwrap();
+        write(
+            edited.join("main.gd"),
+            "extends Node\n\nfunc _ready():\n\tprint('patched')\n",
+        )
+        .unwrap();
+        write(edited.join("sub/new.gd"), "extends RefCounted\n").unwrap();
+
+        let patch = diff(&clean, &edited).unwrap();
+        assert!(patch.contains("diff --git a/main.gd b/main.gd"));
+        apply(&patch, &clean).unwrap();
+        assert_eq!(
+            read_to_string(clean.join("main.gd")).unwrap(),
+            read_to_string(edited.join("main.gd")).unwrap()
+        );
+        assert!(clean.join("sub/new.gd").exists());
+        assert!(!clean.join("sub/gone.gd").exists());
+
+        // applying twice does not work
+        assert!(apply(&patch, &clean).is_err());
+    }
+
+    #[tokio::test]
+    async fn workflow() {
+        let t = crate::test_utils::mktemp().await;
+        let (c, cwd) = (t.2, t.0.path());
+        let mut cfg = crate::config_file::ConfigFile::new(
+            &r#"packages: {"@bendn/test": "2.0.10"}"#.into(),
+            c.clone(),
+        )
+        .await;
+        let mut p = cfg.packages[0].clone();
+        let dir = edit(&mut p, c.clone(), false, cwd).await.unwrap();
+        write(dir.join("patched.gd"), "extends Node\n").unwrap();
+        let file = commit(&mut p, c.clone(), cwd).await.unwrap().unwrap();
+        assert_eq!(file, cwd.join("patches/@bendn+test@2.0.10.patch"));
+        assert!(p.modified_files(cwd).is_empty());
+
+        crate::purge(&mut cfg, crate::Verbosity::Quiet, cwd);
+        crate::update(
+            &mut cfg,
+            crate::UpdateOptions::default(),
+            crate::Verbosity::Quiet,
+            c.clone(),
+            cwd,
+        )
+        .await;
+        assert!(dir.join("patched.gd").exists());
+
+        // no edits => no patch
+        remove_file(dir.join("patched.gd")).unwrap();
+        assert_eq!(commit(&mut p, c, cwd).await.unwrap(), None);
+        assert!(!file.exists());
+    }
+}
@@ -0,0 +1,511 @@
+use semver_rs::Version;
+use std::fs::{read_dir, read_to_string, write};
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+pub const PROJECT_FILE: &str = "project.godot";
+
+/// A `project.godot`. Only the edited values change, everything else (comments, order, formatting) is kept.
+pub struct Project {
+    text: String,
+}
+
+/// A `key=value` in a [Project].
+struct Entry {
+    section: String,
+    key: String,
+    /// The whole line(s), including the newline.
+    line: Range<usize>,
+    value: Range<usize>,
+}
+
+impl Project {
+    pub fn parse(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+        }
+    }
+
+    /// Reads the `project.godot` in `cwd`, if there is one.
+    pub fn read(cwd: &Path) -> Option<Self> {
+        read_to_string(cwd.join(PROJECT_FILE))
+            .ok()
+            .map(|t| Self::parse(&t))
+    }
+
+    pub fn write(&self, cwd: &Path) -> io::Result<()> {
+        write(cwd.join(PROJECT_FILE), &self.text)
+    }
+
+    /// The `config_version`: 4 for godot 3, 5 for godot 4.
+    pub fn config_version(&self) -> Option<u32> {
+        self.get("", "config_version")?.parse().ok()
+    }
+
+    /// The godot versions this project could be using.
+    /// Exact if `config/features` has it (`"4.2"`), otherwise every minor version of the major version its `config_version` is from.
+    pub fn engine_versions(&self) -> Vec<Version> {
+        let features = self
+            .get("application", "config/features")
+            .map_or(vec![], strings);
+        if let Some(v) = features.iter().find_map(|f| parse_version(f)) {
+            return vec![v];
+        }
+        let major = match self.config_version() {
+            Some(5) => 4,
+            Some(4) => 3,
+            _ => return vec![],
+        };
+        (0..10)
+            .filter_map(|minor| parse_version(&format!("{major}.{minor}")))
+            .collect()
+    }
+
+    /// The (source of the) value of `key` in `[section]` (`""` for the keys before any section).
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.entries()
+            .into_iter()
+            .find(|e| e.section == section && e.key == key)
+            .map(|e| &self.text[e.value])
+    }
+
+    /// The keys of `[section]`, with their values.
+    pub fn section(&self, section: &str) -> Vec<(String, String)> {
+        self.entries()
+            .into_iter()
+            .filter(|e| e.section == section)
+            .map(|e| (e.key, self.text[e.value].to_string()))
+            .collect()
+    }
+
+    /// Sets `key` in `[section]` to `value` (its source, so strings need quotes).
+    /// New keys are added at the end of the section, new sections at the end of the file.
+    pub fn set(&mut self, section: &str, key: &str, value: &str) {
+        let entries = self.entries();
+        if let Some(e) = entries
+            .iter()
+            .find(|e| e.section == section && e.key == key)
+        {
+            self.text.replace_range(e.value.clone(), value);
+            return;
+        }
+        let line = format!("{key}={value}\n");
+        let at = match entries.iter().rfind(|e| e.section == section) {
+            Some(e) => e.line.end,
+            None => match self.header(section) {
+                Some(end) => {
+                    // godot leaves a empty line after the header
+                    let blank = self.text[end..].starts_with('\n');
+                    end + usize::from(blank)
+                }
+                None => {
+                    if !self.text.is_empty() && !self.text.ends_with('\n') {
+                        self.text.push('\n');
+                    }
+                    self.text.push_str(&format!("\n[{section}]\n\n"));
+                    self.text.len()
+                }
+            },
+        };
+        self.text.insert_str(at, &line);
+    }
+
+    /// Removes `key` from `[section]`, returning wether it was there.
+    pub fn remove(&mut self, section: &str, key: &str) -> bool {
+        let Some(e) = self
+            .entries()
+            .into_iter()
+            .find(|e| e.section == section && e.key == key)
+        else {
+            return false;
+        };
+        self.text.replace_range(e.line, "");
+        true
+    }
+
+    /// The index after the `[section]` header line.
+    fn header(&self, section: &str) -> Option<usize> {
+        let header = format!("[{section}]");
+        let mut start = 0;
+        for line in self.text.split_inclusive('\n') {
+            start += line.len();
+            if line.trim() == header {
+                return Some(start);
+            }
+        }
+        None
+    }
+
+    fn entries(&self) -> Vec<Entry> {
+        let b = self.text.as_bytes();
+        let mut entries = vec![];
+        let mut section = String::new();
+        let mut i = 0;
+        while i < b.len() {
+            let start = i;
+            let end = self.text[i..].find('\n').map_or(b.len(), |n| i + n + 1);
+            let line = self.text[i..end].trim();
+            if line.starts_with('[') && line.ends_with(']') {
+                section = line[1..line.len() - 1].to_string();
+                i = end;
+                continue;
+            }
+            let Some(eq) = self.text[i..end]
+                .find('=')
+                .filter(|_| !line.starts_with(';'))
+            else {
+                i = end;
+                continue;
+            };
+            let key = self.text[i..i + eq].trim().to_string();
+            let value_start = i + eq + 1;
+            i = value_end(b, value_start);
+            let value = value_start..i;
+            // the (\r)\n
+            i += usize::from(b.get(i) == Some(&b'\r'));
+            i += usize::from(b.get(i) == Some(&b'\n'));
+            entries.push(Entry {
+                section: section.clone(),
+                key,
+                line: start..i,
+                value,
+            });
+        }
+        entries
+    }
+}
+
+/// Where the value starting at `i` ends: at the first newline outside of strings and brackets.
+fn value_end(b: &[u8], mut i: usize) -> usize {
+    let mut depth = 0i32;
+    while i < b.len() {
+        match b[i] {
+            b'"' => {
+                i += 1;
+                while i < b.len() && b[i] != b'"' {
+                    i += if b[i] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            b'\n' if depth <= 0 => return if b[i - 1] == b'\r' { i - 1 } else { i },
+            _ => {}
+        }
+        i += 1;
+    }
+    b.len().min(i)
+}
+
+/// Parses a godot version (`4.2`, `4.2.1`) as semver.
+fn parse_version(v: &str) -> Option<Version> {
+    if !v.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let v = match v.matches('.').count() {
+        1 => format!("{v}.0"),
+        _ => v.to_string(),
+    };
+    Version::new(&v).parse().ok()
+}
+
+/// Wether a package that supports the godot versions in `range` (like `>=4.1`) works with any of the `engines`.
+/// No range, no engines, or a unparsable range are all compatible.
+pub fn compatible(range: Option<&str>, engines: &[Version]) -> bool {
+    let Some(Ok(range)) = range.map(|r| semver_rs::Range::new(r).parse()) else {
+        return true;
+    };
+    engines.is_empty() || engines.iter().any(|e| range.test(e))
+}
+
+/// The strings in a `PackedStringArray("a", "b")` (or `PoolStringArray( "a", "b" )` in godot 3).
+pub fn strings(value: &str) -> Vec<String> {
+    let mut out = vec![];
+    let mut rest = value;
+    while let Some(open) = rest.find('"') {
+        let after = &rest[open + 1..];
+        let Some(close) = after.find('"') else {
+            break;
+        };
+        out.push(after[..close].to_string());
+        rest = &after[close + 1..];
+    }
+    out
+}
+
+/// Writes a string array like godot does, `PackedStringArray("a", "b")` (`PoolStringArray( "a", "b" )` for godot 3).
+pub fn string_array(strings: &[String], godot3: bool) -> String {
+    let quoted = strings
+        .iter()
+        .map(|s| format!("\"{s}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if godot3 {
+        format!("PoolStringArray( {quoted} )")
+    } else {
+        format!("PackedStringArray({quoted})")
+    }
+}
+
+/// The `res://` path of `rel` (relative to the project).
+pub fn res_path(rel: &Path) -> String {
+    format!("res://{}", rel.to_string_lossy().replace('\\', "/"))
+}
+
+/// The `res://` paths of the `plugin.cfg`s in `dir` (relative to `cwd`).
+pub fn plugin_cfgs(dir: &Path, cwd: &Path) -> Vec<String> {
+    let mut out = vec![];
+    let Ok(entries) = read_dir(cwd.join(dir)) else {
+        return out;
+    };
+    for e in entries.filter_map(|e| e.ok()) {
+        let rel = dir.join(e.file_name());
+        if e.file_type().is_ok_and(|t| t.is_dir()) {
+            out.extend(plugin_cfgs(&rel, cwd));
+        } else if e.file_name() == "plugin.cfg" {
+            out.push(res_path(&rel));
+        }
+    }
+    out.sort();
+    out
+}
+
+impl Project {
+    fn is_godot3(&self) -> bool {
+        self.config_version().is_some_and(|v| v < 5)
+    }
+
+    /// The enabled editor plugins.
+    pub fn plugins(&self) -> Vec<String> {
+        self.get("editor_plugins", "enabled")
+            .map_or(vec![], strings)
+    }
+
+    /// Enables the editor `plugins`, returning the ones that were not enabled yet.
+    pub fn enable_plugins(&mut self, plugins: &[String]) -> Vec<String> {
+        let mut enabled = self.plugins();
+        let added = plugins
+            .iter()
+            .filter(|p| !enabled.contains(p))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !added.is_empty() {
+            enabled.extend(added.iter().cloned());
+            let value = string_array(&enabled, self.is_godot3());
+            self.set("editor_plugins", "enabled", &value);
+        }
+        added
+    }
+
+    /// Disables the editor plugins in the `dirs` (`res://` paths), returning the ones that were disabled.
+    pub fn disable_plugins_in(&mut self, dirs: &[String]) -> Vec<String> {
+        let (removed, kept): (Vec<_>, Vec<_>) = self
+            .plugins()
+            .into_iter()
+            .partition(|p| dirs.iter().any(|d| p.starts_with(&format!("{d}/"))));
+        if !removed.is_empty() {
+            if kept.is_empty() {
+                self.remove("editor_plugins", "enabled");
+            } else {
+                let value = string_array(&kept, self.is_godot3());
+                self.set("editor_plugins", "enabled", &value);
+            }
+        }
+        removed
+    }
+}
+
+/// The path of a autoload value, `"*res://x.gd"` => `res://x.gd` (the `*` makes it a global).
+fn autoload_path(value: &str) -> &str {
+    value.trim().trim_matches('"').trim_start_matches('*')
+}
+
+impl Project {
+    /// Registers the `autoloads` (name, `res://` path) as globals.
+    /// Existing autoloads are only replaced if their file is gone (like a pruned version of the package).
+    /// Returns the added names, and the clashes: (name, the path it already has).
+    pub fn add_autoloads(
+        &mut self,
+        autoloads: &[(String, String)],
+        cwd: &Path,
+    ) -> (Vec<String>, Vec<(String, String)>) {
+        let (mut added, mut clashes) = (vec![], vec![]);
+        for (name, path) in autoloads {
+            match self.get("autoload", name).map(autoload_path) {
+                Some(p) if p == path => continue,
+                Some(p) if cwd.join(p.trim_start_matches("res://")).exists() => {
+                    clashes.push((name.clone(), p.to_string()));
+                    continue;
+                }
+                _ => {}
+            }
+            self.set("autoload", name, &format!("\"*{path}\""));
+            added.push(name.clone());
+        }
+        (added, clashes)
+    }
+
+    /// Removes the autoloads in the `dirs` (`res://` paths), returning their names.
+    pub fn remove_autoloads_in(&mut self, dirs: &[String]) -> Vec<String> {
+        let removed = self
+            .section("autoload")
+            .into_iter()
+            .filter(|(_, v)| {
+                dirs.iter()
+                    .any(|d| autoload_path(v).starts_with(&format!("{d}/")))
+            })
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        for name in &removed {
+            self.remove("autoload", name);
+        }
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::*;
+
+    const PROJECT: &str = r#"; Engine configuration file.
+; Do not edit this file manually.
+
+config_version=5
+
+[application]
+
+config/name="Test"
+config/description="a
+multiline = description"
+config/features=PackedStringArray("4.2", "Forward Plus")
+
+[rendering]
+
+environment/defaults/default_clear_color=Color(0.1, 0.1, 0.1, 1)
+"#;
+
+    #[test]
+    fn edit() {
+        let mut p = Project::parse(PROJECT);
+        assert_eq!(p.config_version(), Some(5));
+        assert_eq!(p.get("application", "config/name"), Some("\"Test\""));
+        assert_eq!(
+            p.get("application", "config/description"),
+            Some("\"a\nmultiline = description\"")
+        );
+        assert_eq!(
+            strings(p.get("application", "config/features").unwrap()),
+            ["4.2", "Forward Plus"]
+        );
+        assert_eq!(p.get("application", "multiline "), None);
+
+        p.set("application", "config/name", "\"Renamed\"");
+        p.set("application", "run/main_scene", "\"res://main.tscn\"");
+        p.set(
+            "autoload",
+            "Console",
+            "\"*res://addons/console/console.gd\"",
+        );
+        assert!(p.remove("rendering", "environment/defaults/default_clear_color"));
+        assert!(!p.remove("rendering", "nothing"));
+        assert_eq!(
+            p.text,
+            r#"; Engine configuration file.
+; Do not edit this file manually.
+
+config_version=5
+
+[application]
+
+config/name="Renamed"
+config/description="a
+multiline = description"
+config/features=PackedStringArray("4.2", "Forward Plus")
+run/main_scene="res://main.tscn"
+
+[rendering]
+
+
+[autoload]
+
+Console="*res://addons/console/console.gd"
+"#
+        );
+    }
+
+    #[test]
+    fn plugins() {
+        let mut p = Project::parse(PROJECT);
+        let plugins = ["res://addons/a/plugin.cfg".to_string()];
+        assert_eq!(p.enable_plugins(&plugins), plugins);
+        assert_eq!(p.enable_plugins(&plugins), [] as [String; 0]);
+        p.enable_plugins(&["res://addons/mine/plugin.cfg".into()]);
+        assert_eq!(
+            p.get("editor_plugins", "enabled"),
+            Some(
+                r#"PackedStringArray("res://addons/a/plugin.cfg", "res://addons/mine/plugin.cfg")"#
+            )
+        );
+        assert_eq!(p.disable_plugins_in(&["res://addons/a".into()]), plugins);
+        assert_eq!(p.plugins(), ["res://addons/mine/plugin.cfg"]);
+
+        let mut p = Project::parse("config_version=4\n");
+        p.enable_plugins(&plugins);
+        assert_eq!(
+            p.text,
+            "config_version=4\n\n[editor_plugins]\n\nenabled=PoolStringArray( \"res://addons/a/plugin.cfg\" )\n"
+        );
+    }
+
+    #[test]
+    fn autoloads() {
+        let t = tempfile::TempDir::new().unwrap();
+        let cwd = t.path();
+        std::fs::create_dir_all(cwd.join("addons/mine")).unwrap();
+        std::fs::write(cwd.join("addons/mine/console.gd"), "").unwrap();
+        let mut p = Project::parse(
+            "[autoload]\n\nConsole=\"*res://addons/mine/console.gd\"\nOld=\"*res://addons/__gpm_deps/log/1.0.0/log.gd\"\n",
+        );
+        let (added, clashes) = p.add_autoloads(
+            &[
+                ("Console".into(), "res://addons/console/console.gd".into()),
+                (
+                    "Old".into(),
+                    "res://addons/__gpm_deps/log/1.1.0/log.gd".into(),
+                ),
+                ("New".into(), "res://addons/new/new.gd".into()),
+            ],
+            cwd,
+        );
+        assert_eq!(added, ["Old", "New"]);
+        assert_eq!(
+            clashes,
+            [("Console".into(), "res://addons/mine/console.gd".into())]
+        );
+        assert_eq!(
+            p.get("autoload", "Old"),
+            Some("\"*res://addons/__gpm_deps/log/1.1.0/log.gd\"")
+        );
+        assert_eq!(
+            p.remove_autoloads_in(&[
+                "res://addons/new".into(),
+                "res://addons/__gpm_deps/log/1.1.0".into()
+            ]),
+            ["Old", "New"]
+        );
+        assert_eq!(p.section("autoload").len(), 1);
+    }
+
+    #[test]
+    fn engine() {
+        let v = |s: &str| Version::new(s).parse().unwrap();
+        assert_eq!(Project::parse(PROJECT).engine_versions(), [v("4.2.0")]);
+        let godot3 = Project::parse("config_version=4\n").engine_versions();
+        assert_eq!(godot3.len(), 10);
+        assert!(compatible(Some(">=3.5 <4"), &godot3));
+        assert!(!compatible(Some(">=4.1"), &godot3));
+        assert!(compatible(Some(">=4.1"), &[v("4.2.0")]));
+        assert!(compatible(None, &[v("4.2.0")]));
+        assert!(compatible(Some(">=4.1"), &[]));
+    }
+}
@@ -0,0 +1,225 @@
+use sha1::{Digest, Sha1};
+use std::ops::Range;
+
+/// A attribute of a [Tag], like `path="res://x.gd"`.
+/// `value` is the range of the value, without the quotes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attr {
+    pub key: String,
+    pub value: Range<usize>,
+}
+
+/// A header of a text resource (.tscn/.tres), like
+/// `[ext_resource type="Script" uid="uid://c2ie5" path="res://x.gd" id="1_abc"]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    pub name: String,
+    pub attrs: Vec<Attr>,
+}
+
+impl Tag {
+    pub fn attr(&self, key: &str) -> Option<&Attr> {
+        self.attrs.iter().find(|a| a.key == key)
+    }
+}
+
+/// Finds the headers of a text resource, in both the godot 3 (format=2) and godot 4 (format=3) formats.
+/// Headers start at the beginning of a line, and strings in the properties are skipped.
+pub fn tags(src: &str) -> Vec<Tag> {
+    let b = src.as_bytes();
+    let mut tags = vec![];
+    let mut i = 0;
+    let mut line_start = true;
+    while i < b.len() {
+        match b[i] {
+            b'"' => {
+                i = skip_string(b, i);
+                line_start = false;
+                continue;
+            }
+            b'[' if line_start => {
+                if let Some((tag, end)) = tag(src, i + 1) {
+                    tags.push(tag);
+                    i = end;
+                    line_start = false;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        line_start = b[i] == b'\n';
+        i += 1;
+    }
+    tags
+}
+
+/// Returns the index after the string starting at `i`.
+fn skip_string(b: &[u8], mut i: usize) -> usize {
+    i += 1;
+    while i < b.len() && b[i] != b'"' {
+        i += if b[i] == b'\\' { 2 } else { 1 };
+    }
+    (i + 1).min(b.len())
+}
+
+fn is_ident(c: u8) -> bool {
+    c == b'_' || c == b'/' || c.is_ascii_alphanumeric()
+}
+
+/// Parses a tag (after its `[`). Returns it, and the index after its `]`.
+fn tag(src: &str, mut i: usize) -> Option<(Tag, usize)> {
+    let b = src.as_bytes();
+    let ident = |i: &mut usize| {
+        let start = *i;
+        while *i < b.len() && is_ident(b[*i]) {
+            *i += 1;
+        }
+        &src[start..*i]
+    };
+    let name = ident(&mut i).to_string();
+    // `[1, 2]` or `[Vector2(0, 0)]` are arrays, not tags
+    if name.is_empty() || !matches!(b.get(i), Some(b' ' | b']')) {
+        return None;
+    }
+    let mut attrs = vec![];
+    loop {
+        while i < b.len() && b[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        match b.get(i)? {
+            b']' => return Some((Tag { name, attrs }, i + 1)),
+            _ => {
+                let key = ident(&mut i).to_string();
+                if key.is_empty() || b.get(i) != Some(&b'=') {
+                    return None;
+                }
+                i += 1;
+                let value = if b.get(i) == Some(&b'"') {
+                    let end = skip_string(b, i);
+                    i + 1..end - 1
+                } else {
+                    // numbers, or things like ExtResource("1")
+                    let start = i;
+                    let mut depth = 0;
+                    while i < b.len() {
+                        match b[i] {
+                            b'"' => {
+                                i = skip_string(b, i);
+                                continue;
+                            }
+                            b'(' => depth += 1,
+                            b')' => depth -= 1,
+                            b' ' | b']' | b'\n' if depth == 0 => break,
+                            _ => {}
+                        }
+                        i += 1;
+                    }
+                    start..i
+                };
+                i = value.end + usize::from(b.get(value.end) == Some(&b'"'));
+                attrs.push(Attr { key, value });
+            }
+        }
+    }
+}
+
+/// Encodes a uid like godot does (`ResourceUID::id_to_text`): base 34, with the digits `a-y0-8`.
+pub fn encode_uid(mut id: u64) -> String {
+    const BASE: u64 = 34;
+    let mut digits = vec![];
+    loop {
+        let c = (id % BASE) as u8;
+        digits.push(if c < 25 { b'a' + c } else { b'0' + c - 25 });
+        id /= BASE;
+        if id == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    format!("uid://{}", String::from_utf8(digits).unwrap())
+}
+
+/// The uid of the resource at `path`, if it is in the `deps` folder (`__gpm_deps`, see [crate::package::layout::Layout]).
+/// Every version of a package would have the same uids, so they get regenerated from their path instead.
+/// Only the part after `__gpm_deps/` is used, so that the uid is the same for the staged and the installed path.
+pub fn uid_for(path: &str, deps: &str) -> Option<String> {
+    let path = path.replace('\\', "/");
+    let parts = path.split('/').collect::<Vec<_>>();
+    // a folder, so not the last part
+    let i = parts[..parts.len() - 1].iter().rposition(|p| *p == deps)?;
+    let key = parts[i + 1..].join("/");
+    let hash = Sha1::digest(key.as_bytes());
+    let id = u64::from_be_bytes(hash[..8].try_into().unwrap()) & i64::MAX as u64;
+    Some(encode_uid(id))
+}
+
+/// Replaces the ranges in `src`. The ranges must not overlap.
+pub fn replace(src: &str, mut edits: Vec<(Range<usize>, String)>) -> String {
+    edits.sort_by_key(|(r, _)| r.start);
+    let mut out = String::with_capacity(src.len());
+    let mut last = 0;
+    for (r, with) in edits {
+        out.push_str(&src[last..r.start]);
+        out.push_str(&with);
+        last = r.end;
+    }
+    out.push_str(&src[last..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::*;
+
+    #[test]
+    fn tags() {
+        let src = r#"[gd_scene load_steps=3 format=3 uid="uid://b8lyn7k3w8ey2"]
+
+[ext_resource type="Script" uid="uid://c2ie5" path="res://addons/x/main.gd" id="1_abc"]
+[ext_resource path="res://addons/x/icon.png" type="Texture" id=2]
+
+[node name="Root" type="Node2D"]
+script = ExtResource("1_abc")
+text = "a string
+[ext_resource path=\"res://not_a_tag.gd\"]"
+points = [1, 2]
+"#;
+        let tags = super::tags(src);
+        assert_eq!(
+            tags.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+            ["gd_scene", "ext_resource", "ext_resource", "node"]
+        );
+        let value = |t: &Tag, k: &str| &src[t.attr(k).unwrap().value.clone()];
+        assert_eq!(value(&tags[0], "uid"), "uid://b8lyn7k3w8ey2");
+        assert_eq!(value(&tags[0], "format"), "3");
+        assert_eq!(value(&tags[1], "path"), "res://addons/x/main.gd");
+        assert_eq!(value(&tags[1], "id"), "1_abc");
+        assert_eq!(value(&tags[2], "path"), "res://addons/x/icon.png");
+        assert_eq!(value(&tags[2], "id"), "2");
+    }
+
+    #[test]
+    fn uids() {
+        assert_eq!(encode_uid(0), "uid://a");
+        assert_eq!(encode_uid(25), "uid://0");
+        assert_eq!(encode_uid(34), "uid://ba");
+        assert_eq!(uid_for("res://addons/x/main.gd", "__gpm_deps"), None);
+        // only a whole folder counts
+        assert_eq!(uid_for("res://addons/mydeps/x/main.gd", "deps"), None);
+        assert_eq!(
+            uid_for("res://addons/deps/x/main.gd", "deps"),
+            uid_for("res://addons/__gpm_deps/x/main.gd", "__gpm_deps")
+        );
+        assert_eq!(
+            uid_for(
+                "/tmp/.gpm-staging-1/new/addons/__gpm_deps/@a/b/1.0.0/main.tscn",
+                "__gpm_deps"
+            ),
+            uid_for("res://addons/__gpm_deps/@a/b/1.0.0/main.tscn", "__gpm_deps")
+        );
+        assert_ne!(
+            uid_for("res://addons/__gpm_deps/@a/b/1.0.0/main.tscn", "__gpm_deps"),
+            uid_for("res://addons/__gpm_deps/@a/b/1.0.1/main.tscn", "__gpm_deps")
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ctx;
+
+use anyhow::{Context, Result};
+use std::fs::{create_dir_all, remove_dir_all, rename};
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Stages packages in a hidden directory next to `addons/`, and swaps them in all at once.
+/// If the swap fails halfway, the previous `addons/` is restored. If it is never committed
+/// (say, a download fails), `addons/` is not touched at all.
+pub struct Transaction {
+    cwd: PathBuf,
+    dir: TempDir,
+}
+
+impl Transaction {
+    pub fn new(cwd: &Path) -> Result<Self> {
+        Ok(Self {
+            cwd: cwd.to_path_buf(),
+            dir: ctx!(
+                tempfile::Builder::new()
+                    .prefix(".gpm-staging-")
+                    .tempdir_in(cwd),
+                "creating staging directory in {}",
+                cwd.display()
+            )?,
+        })
+    }
+
+    /// A transaction outside of the project, for looking at staged packages without installing them.
+    /// Never commit it.
+    pub fn scratch() -> Result<Self> {
+        let dir = ctx!(tempfile::tempdir(), "creating temporary directory")?;
+        Ok(Self {
+            cwd: dir.path().to_path_buf(),
+            dir,
+        })
+    }
+
+    /// Where to stage whatever should end up at `cwd/rel`.
+    pub fn staged(&self, rel: &Path) -> PathBuf {
+        self.dir.path().join("new").join(rel)
+    }
+
+    fn backup(&self, rel: &Path) -> PathBuf {
+        self.dir.path().join("old").join(rel)
+    }
+
+    /// Moves the staged directories in place, backing up what was there before.
+    /// Restores the backups if any of them fail.
+    pub fn commit(self, staged: impl IntoIterator<Item = PathBuf>) -> Result<()> {
+        let mut done: Vec<PathBuf> = vec![];
+        for rel in staged {
+            if let Err(e) = self.swap(&rel) {
+                for rel in done.iter().rev() {
+                    self.restore(rel);
+                }
+                return Err(e);
+            }
+            done.push(rel);
+        }
+        Ok(())
+    }
+
+    fn swap(&self, rel: &Path) -> Result<()> {
+        let (new, old, dst) = (self.staged(rel), self.backup(rel), self.cwd.join(rel));
+        if dst.symlink_metadata().is_ok() {
+            create_dir_all(old.parent().unwrap())?;
+            ctx!(rename(&dst, &old), "backing up {}", dst.display())?;
+        }
+        let res = create_dir_all(dst.parent().unwrap()).and_then(|_| rename(&new, &dst));
+        if res.is_err() {
+            self.restore(rel);
+        }
+        ctx!(res, "moving {} in place", dst.display())
+    }
+
+    /// Puts the backup of `rel` back, best effort.
+    fn restore(&self, rel: &Path) {
+        let (old, dst) = (self.backup(rel), self.cwd.join(rel));
+        if dst.is_dir() && !crate::link::is_link(&dst) {
+            let _ = remove_dir_all(&dst);
+        } else if dst.symlink_metadata().is_ok() {
+            let _ = crate::link::unlink(&dst);
+        }
+        if old.symlink_metadata().is_ok() {
+            let _ = rename(&old, &dst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transaction::*;
+    use std::fs::{read_to_string, write};
+
+    #[test]
+    fn rollback() {
+        let t = TempDir::new().unwrap();
+        let cwd = t.path();
+        create_dir_all(cwd.join("addons/a")).unwrap();
+        write(cwd.join("addons/a/main.gd"), "old").unwrap();
+        write(cwd.join("addons/file"), "").unwrap(); // addons/file/b cant be created
+
+        let tx = Transaction::new(cwd).unwrap();
+        for rel in ["addons/a", "addons/file/b"] {
+            create_dir_all(tx.staged(Path::new(rel))).unwrap();
+            write(tx.staged(Path::new(rel)).join("main.gd"), "new").unwrap();
+        }
+        let staging = tx.dir.path().to_path_buf();
+        assert!(tx
+            .commit(["addons/a".into(), "addons/file/b".into()])
+            .is_err());
+        assert_eq!(read_to_string(cwd.join("addons/a/main.gd")).unwrap(), "old");
+        assert!(!staging.exists());
+
+        let tx = Transaction::new(cwd).unwrap();
+        create_dir_all(tx.staged(Path::new("addons/a"))).unwrap();
+        write(tx.staged(Path::new("addons/a/main.gd")), "new").unwrap();
+        tx.commit(["addons/a".into()]).unwrap();
+        assert_eq!(read_to_string(cwd.join("addons/a/main.gd")).unwrap(), "new");
+    }
+}
@@ -1,20 +1,15 @@
 use clap::{builder::PossibleValue, ValueEnum};
 
-#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+#[derive(Clone, Debug, Eq, PartialEq, Copy, Default)]
 pub enum Verbosity {
     Silent,
     Quiet,
+    #[default]
     Normal,
     Verbose,
     // VeryVerbose,
 }
 
-impl Default for Verbosity {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 impl std::str::FromStr for Verbosity {
     type Err = String;
 
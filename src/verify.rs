@@ -0,0 +1,290 @@
+use crate::config_file::ConfigFile;
+use crate::package::meta::{Meta, META_FILE};
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+/// Something wrong with the installed packages, found by [verify].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Problem {
+    /// There is no lockfile (or it cant be read).
+    NoLock,
+    /// A package is not installed.
+    Missing(String),
+    /// A package is not in the lockfile.
+    Unlocked(String),
+    /// A package has no install marker, so it cant be verified.
+    Unverifiable(String),
+    /// The installed version is not the wanted one: (package, installed version).
+    Version(String, String),
+    /// Files were edited since installing: (package, files).
+    Modified(String, Vec<PathBuf>),
+    /// A installed directory that no package owns.
+    Extra(PathBuf),
+    /// Several installed scripts declare the same global `class_name`: (class name, the packages).
+    ClassName(String, Vec<String>),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::NoLock => write!(f, "no lockfile (run gpm update)"),
+            Problem::Missing(p) => write!(f, "{p} is not installed"),
+            Problem::Unlocked(p) => write!(f, "{p} is not in the lockfile"),
+            Problem::Unverifiable(p) => write!(f, "{p} has no install marker (reinstall it)"),
+            Problem::Version(p, v) => write!(f, "{p} is installed at version {v}"),
+            Problem::Modified(p, files) => {
+                write!(f, "{p} was modified: ")?;
+                let files = files
+                    .iter()
+                    .map(|f| f.display().to_string())
+                    .collect::<Vec<_>>();
+                write!(f, "{}", files.join(", "))
+            }
+            Problem::Extra(d) => write!(f, "{} is not owned by any package", d.display()),
+            Problem::ClassName(c, packages) => {
+                write!(
+                    f,
+                    "class_name {c} is declared by {}",
+                    packages.join(" and ")
+                )
+            }
+        }
+    }
+}
+
+/// Checks the installed packages against the lockfile (its contents, if there is one)
+/// and their install markers. Linked packages are only checked for existence.
+pub fn verify(cfg: &mut ConfigFile, lock: Option<&str>, cwd: &Path) -> Vec<Problem> {
+    #[derive(Deserialize)]
+    struct Locked {
+        name: String,
+        version: String,
+    }
+    let mut problems = vec![];
+    let locked = match lock.map(serde_json::from_str::<Vec<Locked>>) {
+        Some(Ok(l)) => Some(l),
+        _ => {
+            problems.push(Problem::NoLock);
+            None
+        }
+    };
+    let mut packages = cfg.collect().into_iter().collect::<Vec<_>>();
+    packages.sort();
+    for p in packages {
+        let dir = p.download_dir(cwd);
+        let version = p.manifest.version.to_string();
+        if let Some(locked) = &locked {
+            if !locked
+                .iter()
+                .any(|l| l.name == p.name && l.version == version)
+            {
+                problems.push(Problem::Unlocked(p.to_string()));
+            }
+        }
+        if !p.is_installed(cwd) {
+            problems.push(Problem::Missing(p.to_string()));
+            continue;
+        }
+        if p.is_linked(cwd) {
+            continue;
+        }
+        let Some(meta) = Meta::read(&dir) else {
+            problems.push(Problem::Unverifiable(p.to_string()));
+            continue;
+        };
+        if meta.origin.version != version {
+            problems.push(Problem::Version(p.to_string(), meta.origin.version));
+        }
+        let modified = p.modified_files(cwd);
+        if !modified.is_empty() {
+            problems.push(Problem::Modified(p.to_string(), modified));
+        }
+    }
+    problems.extend(
+        orphaned(cfg, cwd)
+            .into_iter()
+            .map(|d| Problem::Extra(d.strip_prefix(cwd).map_or(d.clone(), Path::to_path_buf))),
+    );
+    problems.extend(class_name_collisions(cfg, cwd));
+    problems
+}
+
+/// Finds global `class_name`s declared by more than one installed package (or version of one),
+/// which godot refuses to load.
+pub fn class_name_collisions(cfg: &mut ConfigFile, cwd: &Path) -> Vec<Problem> {
+    let mut classes = BTreeMap::<String, Vec<String>>::new();
+    let mut packages = cfg.collect().into_iter().collect::<Vec<_>>();
+    packages.sort();
+    for p in packages {
+        let mut names = BTreeSet::new();
+        class_names(&p.download_dir(cwd), &mut names);
+        for name in names {
+            classes.entry(name).or_default().push(p.to_string());
+        }
+    }
+    classes
+        .into_iter()
+        .filter(|(_, packages)| packages.len() > 1)
+        .map(|(c, packages)| Problem::ClassName(c, packages))
+        .collect()
+}
+
+fn class_names(dir: &Path, out: &mut BTreeSet<String>) {
+    for d in dirs(dir) {
+        class_names(&d, out);
+    }
+    let Ok(entries) = read_dir(dir) else {
+        return;
+    };
+    for e in entries.filter_map(|e| e.ok()) {
+        let path = e.path();
+        if path.extension().is_some_and(|e| e == "gd") {
+            if let Some(c) = read_to_string(&path)
+                .ok()
+                .and_then(|s| crate::gdscript::class_name(&s).map(String::from))
+            {
+                out.insert(c);
+            }
+        }
+    }
+}
+
+/// The download directories of every package in `cfg`.
+fn owned(cfg: &mut ConfigFile, cwd: &Path) -> HashSet<PathBuf> {
+    cfg.collect()
+        .into_iter()
+        .map(|p| p.download_dir(cwd))
+        .collect()
+}
+
+/// The directories gpm installed that no package in `cfg` owns (anymore), sorted:
+/// old `__gpm_deps/<name>/<version>`s, and packages that were removed from the config.
+pub fn orphaned(cfg: &mut ConfigFile, cwd: &Path) -> Vec<PathBuf> {
+    let owned = owned(cfg, cwd);
+    let mut out = vec![];
+    extra_markers(&cwd.join(&cfg.layout.root), &owned, &mut out);
+    extra_deps(&cfg.layout.deps_dir(cwd), &owned, &mut out);
+    out.sort();
+    out.dedup();
+    out
+}
+
+fn dirs(dir: &Path) -> Vec<PathBuf> {
+    read_dir(dir).map_or(vec![], |r| {
+        r.filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+            .map(|e| e.path())
+            .collect()
+    })
+}
+
+/// Finds directories that were installed by gpm (have a install marker), but are not owned.
+/// Other directories in the install root are assumed to be the projects own addons.
+fn extra_markers(dir: &Path, owned: &HashSet<PathBuf>, out: &mut Vec<PathBuf>) {
+    for d in dirs(dir) {
+        if owned.contains(&d) {
+            continue;
+        }
+        if d.join(META_FILE).exists() {
+            out.push(d);
+        } else {
+            extra_markers(&d, owned, out);
+        }
+    }
+}
+
+/// Finds `__gpm_deps/<name>/<version>` directories that are not owned. Everything in there belongs to gpm.
+fn extra_deps(dir: &Path, owned: &HashSet<PathBuf>, out: &mut Vec<PathBuf>) {
+    for name in dirs(dir) {
+        if name.file_name().unwrap().to_string_lossy().starts_with('@') {
+            extra_deps(&name, owned, out); // a scope
+            continue;
+        }
+        for version in dirs(&name) {
+            if !owned.contains(&version) {
+                out.push(version);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::verify::*;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+
+    #[tokio::test]
+    async fn verify() {
+        let t = crate::test_utils::mktemp().await;
+        let (c, cwd) = (t.2, t.0.path());
+        let mut cfg =
+            ConfigFile::new(&r#"packages: {"@bendn/test": "2.0.10"}"#.into(), c.clone()).await;
+        crate::update(
+            &mut cfg,
+            crate::UpdateOptions::default(),
+            crate::Verbosity::Quiet,
+            c.clone(),
+            cwd,
+        )
+        .await;
+        let lock = cfg.lock(cwd);
+        assert_eq!(super::verify(&mut cfg, Some(&lock), cwd), []);
+
+        write(
+            cwd.join("addons/@bendn/test/new.gd"),
+            "class_name Parser\nextends Node\n",
+        )
+        .unwrap();
+        write(
+            cwd.join("addons/__gpm_deps/@bendn/gdcli/1.2.5/x.gd"),
+            "class_name Parser\n",
+        )
+        .unwrap();
+        assert_eq!(
+            class_name_collisions(&mut cfg, cwd),
+            [Problem::ClassName(
+                "Parser".into(),
+                vec!["@bendn/gdcli@1.2.5".into(), "@bendn/test@2.0.10".into()]
+            )]
+        );
+
+        create_dir_all(cwd.join("addons/my_own_addon")).unwrap(); // not gpm's
+        create_dir_all(cwd.join("addons/__gpm_deps/@bendn/gone/1.0.0")).unwrap();
+        // removed from the config
+        create_dir_all(cwd.join("addons/@bendn/dropped")).unwrap();
+        write(cwd.join("addons/@bendn/dropped").join(META_FILE), "{}").unwrap();
+        write(cwd.join("addons/@bendn/test/new.gd"), "").unwrap();
+        remove_dir_all(cwd.join("addons/__gpm_deps/@bendn/gdcli")).unwrap();
+        assert_eq!(
+            super::verify(&mut cfg, None, cwd),
+            [
+                Problem::NoLock,
+                Problem::Missing("@bendn/gdcli@1.2.5".into()),
+                Problem::Modified(
+                    "@bendn/test@2.0.10".into(),
+                    vec!["addons/@bendn/test/new.gd".into()]
+                ),
+                Problem::Extra("addons/@bendn/dropped".into()),
+                Problem::Extra("addons/__gpm_deps/@bendn/gone/1.0.0".into()),
+            ]
+        );
+
+        let gone = cwd.join("addons/__gpm_deps/@bendn/gone/1.0.0");
+        assert_eq!(
+            crate::prune(&mut cfg, true, false, crate::Verbosity::Quiet, cwd),
+            2
+        );
+        assert!(gone.exists());
+        assert_eq!(
+            crate::prune(&mut cfg, false, false, crate::Verbosity::Quiet, cwd),
+            2
+        );
+        assert!(!cwd.join("addons/__gpm_deps/@bendn").exists());
+        assert!(!cwd.join("addons/@bendn/dropped").exists());
+        assert!(cwd.join("addons/my_own_addon").exists());
+    }
+}
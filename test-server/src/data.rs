@@ -47,6 +47,11 @@ lazy_static! {
 ("type-is", r#"{"versions":{"1.6.5":{"dist":{"shasum":"92129495c7b7563eaf923b447382c6c471f95de4","tarball":"{REGISTRY}/type-is/-/type-is-1.6.5.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.1.3"},"version":"1.6.5"},"1.5.1":{"dist":{"shasum":"5c1e62d874f79199fb16b34d16972dba376ccbed","tarball":"{REGISTRY}/type-is/-/type-is-1.5.1.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.0.1"},"version":"1.5.1"},"1.5.2":{"dist":{"shasum":"8291bbe845a904acfaffd05a41fdeb234bfa9e5f","tarball":"{REGISTRY}/type-is/-/type-is-1.5.2.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.0.2"},"version":"1.5.2"},"1.6.11":{"dist":{"shasum":"42ecde7970f2363738b986c0351efba5aa531648","tarball":"{REGISTRY}/type-is/-/type-is-1.6.11.tgz"},"dependencies":{"mime-types":"~2.1.9","media-typer":"0.3.0"},"version":"1.6.11"},"1.6.12":{"dist":{"shasum":"0352a9dfbfff040fe668cc153cc95829c354173e","tarball":"{REGISTRY}/type-is/-/type-is-1.6.12.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.1.10"},"version":"1.6.12"},"1.3.1":{"dist":{"shasum":"a6789b5a52138289ade1ef8f6d9f2874ffd70b6b","tarball":"{REGISTRY}/type-is/-/type-is-1.3.1.tgz"},"dependencies":{"media-typer":"0.2.0","mime-types":"1.0.0"},"version":"1.3.1"},"1.5.5":{"dist":{"shasum":"45248af57f96366d0326ea0868f6bc8607dc4b21","tarball":"{REGISTRY}/type-is/-/type-is-1.5.5.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.0.7"},"version":"1.5.5"},"1.4.0":{"dist":{"shasum":"de51d78a2ccb19a8fa2e137b06784f6b39a88059","tarball":"{REGISTRY}/type-is/-/type-is-1.4.0.tgz"},"dependencies":{"mime-types":"~2.0.0","media-typer":"0.2.0"},"version":"1.4.0"},"1.2.2":{"dist":{"shasum":"dfdbf7cffa57cea0f9b1b55b96f629454e0eee97","tarball":"{REGISTRY}/type-is/-/type-is-1.2.2.tgz"},"dependencies":{"mime-types":"1.0.0"},"version":"1.2.2"},"1.6.10":{"dist":{"shasum":"d27e995b20d8c2a543f3420573f690a3929fd75a","tarball":"{REGISTRY}/type-is/-/type-is-1.6.10.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.1.8"},"version":"1.6.10"},"1.6.15":{"dist":{"shasum":"cab10fb4909e441c82842eafe1ad646c81804410","tarball":"{REGISTRY}/type-is/-/type-is-1.6.15.tgz"},"dependencies":{"mime-types":"~2.1.15","media-typer":"0.3.0"},"version":"1.6.15"},"1.5.7":{"dist":{"shasum":"b9368a593cc6ef7d0645e78b2f4c64cbecd05e90","tarball":"{REGISTRY}/type-is/-/type-is-1.5.7.tgz"},"dependencies":{"mime-types":"~2.0.9","media-typer":"0.3.0"},"version":"1.5.7"},"1.6.9":{"dist":{"shasum":"87f3e88b92ff5ac30fbc1acf9a9d00cbc38b3d7a","tarball":"{REGISTRY}/type-is/-/type-is-1.6.9.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.1.7"},"version":"1.6.9"},"1.1.0":{"dist":{"shasum":"d0245ec8b2676668d59dd0cf3255060676a57db6","tarball":"{REGISTRY}/type-is/-/type-is-1.1.0.tgz"},"dependencies":{"mime":"~1.2.11"},"version":"1.1.0"},"1.6.14":{"dist":{"shasum":"e219639c17ded1ca0789092dd54a03826b817cb2","tarball":"{REGISTRY}/type-is/-/type-is-1.6.14.tgz"},"dependencies":{"mime-types":"~2.1.13","media-typer":"0.3.0"},"version":"1.6.14"},"1.0.0":{"dist":{"shasum":"4ff424e97349a1ee1910b4bfc488595ecdc443fc","tarball":"{REGISTRY}/type-is/-/type-is-1.0.0.tgz"},"dependencies":{"mime":"~1.2.11"},"version":"1.0.0"},"1.6.17":{"dist":{"shasum":"9ef72233f08ffbe83b8fa3c93f4f93ecbc330bc2","tarball":"{REGISTRY}/type-is/-/type-is-1.6.17.tgz"},"dependencies":{"mime-types":"~2.1.24","media-typer":"0.3.0"},"version":"1.6.17"},"1.0.1":{"dist":{"shasum":"ae09d93953c7846f5c083192837575ab363408f1","tarball":"{REGISTRY}/type-is/-/type-is-1.0.1.tgz"},"dependencies":{"mime":"~1.2.11"},"version":"1.0.1"},"1.5.6":{"dist":{"shasum":"5be39670ac699b4d0f59df84264cb05be1c9998b","tarball":"{REGISTRY}/type-is/-/type-is-1.5.6.tgz"},"dependencies":{"mime-types":"~2.0.8","media-typer":"0.3.0"},"version":"1.5.6"},"1.6.1":{"dist":{"shasum":"49addecb0f6831cbc1d34ba929f0f3a4f21b0f2e","tarball":"{REGISTRY}/type-is/-/type-is-1.6.1.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.0.10"},"version":"1.6.1"},"1.6.0":{"dist":{"shasum":"efcb9223fafad5a03be14d8f6c9e1785f2c0e7c3","tarball":"{REGISTRY}/type-is/-/type-is-1.6.0.tgz"},"dependencies":{"mime-types":"~2.0.9","media-typer":"0.3.0"},"version":"1.6.0"},"1.6.2":{"dist":{"shasum":"694e83e5d110417e681cea278227f264ae406e33","tarball":"{REGISTRY}/type-is/-/type-is-1.6.2.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.0.11"},"version":"1.6.2"},"1.2.0":{"dist":{"shasum":"a9aaa3f2014850d4813663f6c714cf6318195138","tarball":"{REGISTRY}/type-is/-/type-is-1.2.0.tgz"},"dependencies":{"mime":"1.2.11"},"version":"1.2.0"},"1.3.2":{"dist":{"shasum":"4f2a5dc58775ca1630250afc7186f8b36309d1bb","tarball":"{REGISTRY}/type-is/-/type-is-1.3.2.tgz"},"dependencies":{"media-typer":"0.2.0","mime-types":"~1.0.1"},"version":"1.3.2"},"1.6.13":{"dist":{"shasum":"6e83ba7bc30cd33a7bb0b7fb00737a2085bf9d08","tarball":"{REGISTRY}/type-is/-/type-is-1.6.13.tgz"},"dependencies":{"mime-types":"~2.1.11","media-typer":"0.3.0"},"version":"1.6.13"},"1.6.3":{"dist":{"shasum":"d87d201777f76dfc526ac202679715d41a28c580","tarball":"{REGISTRY}/type-is/-/type-is-1.6.3.tgz"},"dependencies":{"mime-types":"~2.1.1","media-typer":"0.3.0"},"version":"1.6.3"},"1.6.6":{"dist":{"shasum":"398799519b62360f55c3cd6c486294531975926c","tarball":"{REGISTRY}/type-is/-/type-is-1.6.6.tgz"},"dependencies":{"mime-types":"~2.1.4","media-typer":"0.3.0"},"version":"1.6.6"},"1.6.4":{"dist":{"shasum":"d76fe92f0bcf7b0cf16b64d095e248f71079c318","tarball":"{REGISTRY}/type-is/-/type-is-1.6.4.tgz"},"dependencies":{"mime-types":"~2.1.2","media-typer":"0.3.0"},"version":"1.6.4"},"1.6.16":{"dist":{"shasum":"f89ce341541c672b25ee7ae3c73dee3b2be50194","tarball":"{REGISTRY}/type-is/-/type-is-1.6.16.tgz"},"dependencies":{"mime-types":"~2.1.18","media-typer":"0.3.0"},"version":"1.6.16"},"1.6.18":{"dist":{"shasum":"4e552cd05df09467dcbc4ef739de89f2cf37c131","tarball":"{REGISTRY}/type-is/-/type-is-1.6.18.tgz"},"dependencies":{"mime-types":"~2.1.24","media-typer":"0.3.0"},"version":"1.6.18"},"1.5.0":{"dist":{"shasum":"e3539711529c5ee4e7cd9f5bed27487cb819f823","tarball":"{REGISTRY}/type-is/-/type-is-1.5.0.tgz"},"dependencies":{"mime-types":"~2.0.0","media-typer":"0.2.0"},"version":"1.5.0"},"1.2.1":{"dist":{"shasum":"73d448080a4f1dd18acb1eefff62968c5b5d54a2","tarball":"{REGISTRY}/type-is/-/type-is-1.2.1.tgz"},"dependencies":{"mime-types":"1.0.0"},"version":"1.2.1"},"1.5.3":{"dist":{"shasum":"b7fb92d0abc628393f10dd260932cca65fe9ff68","tarball":"{REGISTRY}/type-is/-/type-is-1.5.3.tgz"},"dependencies":{"mime-types":"~2.0.3","media-typer":"0.3.0"},"version":"1.5.3"},"1.6.7":{"dist":{"shasum":"5ec2bc7c7debc37f586d518c0747ab901f76bcec","tarball":"{REGISTRY}/type-is/-/type-is-1.6.7.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.1.5"},"version":"1.6.7"},"1.3.0":{"dist":{"shasum":"131df06aca1476419f95de3e38f2efef8b249c20","tarball":"{REGISTRY}/type-is/-/type-is-1.3.0.tgz"},"dependencies":{"mime-types":"1.0.0","media-typer":"0.2.0"},"version":"1.3.0"},"1.6.8":{"dist":{"shasum":"3bac8c0c852754c855143e206d4a16e908bf0315","tarball":"{REGISTRY}/type-is/-/type-is-1.6.8.tgz"},"dependencies":{"media-typer":"0.3.0","mime-types":"~2.1.6"},"version":"1.6.8"},"1.5.4":{"dist":{"shasum":"f2afe8635dcf2d159096202be6e120423fa19837","tarball":"{REGISTRY}/type-is/-/type-is-1.5.4.tgz"},"dependencies":{"mime-types":"~2.0.4","media-typer":"0.3.0"},"version":"1.5.4"}}}"#),
 ("utils-merge", r#"{"versions":{"1.0.0":{"dist":{"shasum":"0294fb922bb9375153541c4f7096231f287c8af8","tarball":"{REGISTRY}/utils-merge/-/utils-merge-1.0.0.tgz"},"dependencies":{},"version":"1.0.0"},"1.0.1":{"dist":{"shasum":"9f95710f50a267947b2ccc124741c1028427e713","tarball":"{REGISTRY}/utils-merge/-/utils-merge-1.0.1.tgz"},"dependencies":{},"version":"1.0.1"}}}"#),
 ("vary", r#"{"versions":{"1.1.2":{"dist":{"shasum":"2299f02c6ded30d4a5961b0b9f74524a18f634fc","tarball":"{REGISTRY}/vary/-/vary-1.1.2.tgz"},"dependencies":{},"version":"1.1.2"},"1.0.1":{"dist":{"shasum":"99e4981566a286118dfb2b817357df7993376d10","tarball":"{REGISTRY}/vary/-/vary-1.0.1.tgz"},"dependencies":{},"version":"1.0.1"},"0.1.0":{"dist":{"shasum":"df0945899e93c0cc5bd18cc8321d9d21e74f6176","tarball":"{REGISTRY}/vary/-/vary-0.1.0.tgz"},"dependencies":{},"version":"0.1.0"},"1.0.0":{"dist":{"shasum":"c5e76cec20d3820d8f2a96e7bee38731c34da1e7","tarball":"{REGISTRY}/vary/-/vary-1.0.0.tgz"},"dependencies":{},"version":"1.0.0"},"0.0.0":{"dist":{"shasum":"9edfb6837236e6fa500788995cd85a11c62c482d","tarball":"{REGISTRY}/vary/-/vary-0.0.0.tgz"},"dependencies":{},"version":"0.0.0"},"1.1.0":{"dist":{"shasum":"e1e5affbbd16ae768dd2674394b9ad3022653140","tarball":"{REGISTRY}/vary/-/vary-1.1.0.tgz"},"dependencies":{},"version":"1.1.0"},"1.1.1":{"dist":{"shasum":"67535ebb694c1d52257457984665323f587e8d37","tarball":"{REGISTRY}/vary/-/vary-1.1.1.tgz"},"dependencies":{},"version":"1.1.1"}}}"#),
+("asset-library/api/asset/1234", r#"{"asset_id":"1234","title":"Splitter","version_string":"1.1","godot_version":"4.0","download_provider":"GitHub","download_url":"{REGISTRY}/assets/splitter-main.zip","download_hash":"5308fa44bb94fecd40ca355edb60669bed141b5a3c30f12b05ccf47d0734f0c5"}"#),
+("asset-library/api/asset/1236", r#"{"asset_id":"1236","title":"Bare Splitter","version_string":"1.1","godot_version":"4.0","download_provider":"Custom","download_url":"{REGISTRY}/assets/splitter-bare.zip","download_hash":"00961c77d463a722d355f0306a031ef1a0f966e6491d89e38540798def74bea0"}"#),
+("asset-library/api/asset", r#"{"result":[{"asset_id":"1234","title":"Splitter"},{"asset_id":"1235","title":"Splitter Extras"}],"page":0,"pages":1}"#),
+("@bendn/plugin", r#"{"versions":{"1.0.0":{"dist":{"shasum":"20118b15b77bc9d644b18b43c4d9a52103bf1e98","tarball":"{REGISTRY}/@bendn/plugin/-/plugin-1.0.0.tgz"},"dependencies":{},"version":"1.0.0"}}}"#),
+("@bendn/filtered", r#"{"versions":{"1.0.0":{"dist":{"shasum":"836ebf861428f553f335fa0ceb11a16d15b2656a","tarball":"{REGISTRY}/@bendn/filtered/-/filtered-1.0.0.tgz"},"dependencies":{},"version":"1.0.0"}}}"#),
     ]);
     pub static ref TARBALLS: HashMap<&'static str, Vec<u8>> = HashMap::from([
 ("buffer-crc32/-/buffer-crc32-0.2.3.tgz", hex::decode("1f8b0800000000000003ed5acd731c3776f779fe0a58ae0ac9ddd1104037d0dd92ad2c458eacc952a48aa4ec75a9b4163ec996677a26dd33a2e82dbb72cb2195e39e7388f7b039a5f690fc3bba6c6aff8c3c3c60f821d112bd96e5da845dac190e80f7f5c37b0f0f68cc94f94a1dbaf559fc1e3ceba6cd07eff8a194ca3c27e1bb9002bf298fbfe1611993e403c63341a9605c161f40136505f4bf6b452e7b16dd5cb5a0ca8fe5138d21a7df7f23cfef7a84dc508bf9d1b4bd718bdcb8dbd6aa21ff3020775bd734f0efc73ab48ca7cf5d773c6d6df7abc389aac703339ddc21ab47f3f9ecd6faba7ea607f574ed463ff06ad4c4054e7ae1bd6b6f9ad6643cf658d799b69ecdeb6913066c90d9a275e4997aae623bd9dcdbcc3851e3c3695bcf8f26647ea4e6643656271d696ae3c831b4125d37aa3d2156cd5564fbdcb55d6249077c90c556336de66dad17f369db41d7636823e477f8794ec9cfc6cad693ba25bf5e7cddb879377d8ed438e610a42d741835a9c147beeae6aebd819ddfc0e7139472349db819844d1815c0e8008d4817105a7f05bbf5d73169dd6cdad5a0e409b088eadd989fcc9021304adadc58b4e3d472750183400fe4dfa020983584a86eac7b01511ec547e0bb73b25d370fc306ebcdd4ba2f2753bb18bb6e7d00a8afcfd58c84fe6efd1783f01db89cf2b76ee68075636a87ec96adcfb75ee94872d42c88f936cc9838e33245ef50e3578962af6b0eebe63c97a06360f30be4d0fba6f77347d3dfde93f2fefaa0994deac366daba772fe3cdf91f5600f89fb1a228b24c665240fee722cfaef3fffb78ce87f9cfadcbf5f3fe9f65fcef0d37b61e0c0713fb13c87873fc732979a8ff28843fa75c6421fe2963d7f1ff3e9e8fc8f99aa1d77bfce1e3bb8b7a6cc9fe5ccd17dd93d56559d33903e5da60deaae77577d3d483697bf8e6fa63d61cfebd6e55638e3e99a8503dad3d59568c57e7b2d6ebe1772c06a1ffabeeb53a90a8c6120f824e883952ad32208b7450cef5c974319f2de65d2fb2ec930ed63867c9b4258b26fd7fcae14875b1be19f47a5baead9f43a76fa7a10e75a45393d9d8851295d4e1bf896b0020285648dde080873b9f926ee64ced6b831db74832f6f8f878709ca1a5077beb306efda3ad9bc06863168a9cfa45aff7113001371c8f7b4f9f3eedc14abcfc7d717242278c752f5097f0f359d77bae5a1211fa84b4ee1f1775eb5657ce93adacddeeadaf5f800e7b2329fc0f8477b165f5317d41699fd01745869f023fb185e3a7e4d8829fd2874f4a9facc5195a05566b0424ddbc433e8e0c49951301e06a92ab3bbda045c0184af3e7aea9a1ba7364e260e7613be261460edd7c5e378797cd51ddc01c46af8a2de785dd6425155555f0ac4c639674e747f1bc2cab02b698252a725c03ba06dc92cca70910527b7208b3de10453ad83c3487e02f5372325dc0c026105900d7ccc72764d1b9a0b20329672ed7819378373e4970acfce59fffe3cffff2fbbffce98f7ffef73ffdcf1fff6de555708c2634234c11232238c109e334814f29f40e024bb30315a7385933d5ce6b35de6c0dcc599272e44ec20c7f5f3759e99f6b7fc3c06e31bbead04b797a88c78bc3743bed5e19f90a027901614b842005bdb3f46e8cc0dec1510dce9a02121c401daa1012186931a6c9d493afc7b55ee992fb47571a902fe27c9176d1f4c27064888eff344456f8f994aca65001016ab616e476cec1d6b39bf62ed9c7a9b17b019b52ade6e6c83dc7ad5188ad3e790a9f83e83d03d4e269e40160cea62d885d7a5613b2104e70ebe68bb6b9e8da6415b25b03f3feabf9b3a3e9787aac4eccd1e2abb541506c0c5bdfa673bd07a383f5df30f68ef738cbf57f7bb439dcd91fbe5be6e979cbfa4f45a8ff39a3a2c86986eb3f1399b85effdfc7b3399d9db4f5e1d19cac9a35027557465e3d03eaf51eba7652771dae761d3972add327e410967608cd3eac91103a108d21111eba7e48a8aa392133c8884030d57308dd90d721d18130d283a1f310deddd4cf8f55eb302c54d74d4dad42acdba9599c2dafbe869d490810476eec278a1b6b28c53a350ea912c37cd987910e2902022d24711398f46194192f6cd062d93dae27751211c811832e7085dcde27bda06a3f64dfda876f8796cd167a5c77477d5808ba78ba048d5d68c410ed074bd6a7a1f6088bcb745683e668ad23bda57e3828c8990550e70926947c7c948a8d535b00250fd902643aa4b153400db40399cf60250a4d61bc9f8e216904eb606db57530aabbd583240ad86aa8ab10f838cbcd741e8ed250873009b3b3a94d5ddd11161e2e6186298a84a6ded2a236d80c61d384bc4e42a20b0297962e9587e475707f48f677ef1d7cbeb13724a37df2706ff7b3d1d6708bdcd8d887df37fae4f3d1c1fddd47070446ec6dec1c7c4176ef918d9d2fc8af473b5b7d32fccdc3bde1fe3ed9dd23a3070fb7474368eb8d7636b71f6d8da0dcba0b843bbb07647b04b911b81eec922031f11a0df703b707c3bdcdfbf073e3ee687b74f0459fdc1b1dec04a6f780eb0679b8b17730da7cb4bdb1477a0f1fed3ddcdd1f82025bc07767b4736f0fc40c1f0c770e0664b4036d64f819fc20fbf737b6b751d6c623d07f0f35dcdc7df8c5dee8d3fb07e4feeef6d6101aef0e41b58dbbdbc31eca02b336b737460ffa646be3c1c6a743a4da052e7b382ca9f7f9fd213681bc0df8db3c18edee043b3677770ef6e0671fccdc3b3825fd7cb43fecf736f646fb01917b7bbbc03e000a14bbc804e87686914b009b5c981318127e3fda1f9ee9b235dcd8065efb81f8fce0c1f5e9daffc167b9fe2fcf857f0a196f5eff0b99f1b3f73f8c85f73f9c15d7ef7fdecb134af7548dbfb67f5c591bc4aedb3d2cf161cffae541c8673034bc5009bbbff8e0aeb0a045462b19fe778e3ac9b809ff5715ad04d30a778b85b42667551fa90b2a95cf4bdc47ba4a660aaa3ea47032af84c23d287556976516f79d9535ba5479a476d40a5731871485e5b62a4ba4a8b4cc0dd748e1342b8cb628a1d025b7b488d415d59ed98a851e6675c1c0457157ab3454a41ee5f94c5705cb916b996b9733eb22355356d9bc40bed25aeb7287f27c6e732d04722d339b95c214282193a62a858cd432975a950651f356725f15884ea9a4309543d4584e9930396223332aa5b10935afa8cfac44744a4b4b6a3da29669e93835a86d6e6405f534626385a439730935c56551b0b883cf0ccd5c6e51db5c53d03d476d2da7b6141ead53822a2da48ed499d042951eb5cdb9e65529515babb53695c5b957461b5fe5685dc62d8c7059a4ce05a86ae2a9823556526bd03ea52dcbaca8f09c41da2aa30ab90ac09bd24c456a53da42c0563ff4686fa9640ce571a6730fa82385d499c9396263bc5695a8126abad4166c4179bca45c9715a2233c15654911352329a0ac111bcda876154fa8712f7d614a444794b2cc0d43d40c032dacc2b9d7524a6e33442db9398dd43439187a6a6939a71aed73de0a4639a25930cd4a512206546aa905f3c953bdf62e57689f2b7569f32c9ece94b43095426da9a7d457197a702569a5ca3279aa63d4552543fb0a2f1585a9428a5282ae1cb5ad1878a4a9d03a2765260c65c953a322c8971969248b2740e0ca220327410fe692539a239ad2505909d814c428d1942951a0bc92d3d2036c4801f3607281f2a4d0d45606b1615c17ae1209b5526ba74b87e878a321ba0b13cfa0ac65d6e24c326115b739a2561a9b15c6275ff31abcd948442db79a4b2650db4c85f834a8adcab481f507ad83b8d519753c79aab25e891cb5cdac2d2b61515b05d1627289a8017cd2e71eadcb335941064b9e9ae5d2d9caa3b6cacaa22c73b4cf2a4961fed083f39ce6dcc673b62ca319b8ba4811aaa8ca8d40fbaca5b63006ed0bcb63c132e4ca0b584099426cb4a3e0ac2ca1662a6a006a94270a596ac1453cbf93be141ae5e94a4a9b53e46a204fbbbc4abe0653e77c45111d5ec1bc5415a2067344ab92e3dc9bc216aad4889aa874965996722a773a07a740d474a1ad3019a263a856d2a89885633a470c2aa5bd86b495a2242530f4d45c33cb2bb4cf292bf222436d2b6b7951a878f298a635ad259985602cd13e9741c6d40cb5ad729967ba446da99556aa0cad2b945442a93245494e9df114b585f453795fa1b65451aa1cc7b92f60eda8603d417f8ce196b24359d0524130a1473aca20221035595169b84779be905c1402b996540a09cb528a924a9a4c16c8573aa9a97428cf3b9b335de04c9695cd384c344aa05615a07b8ad0c2da5c1944cd57bab23e9ecb964e3b0766204501b3e172c4063c9096ce26d41220a8ad6296555981da66a52d0d37885aeeadf75ca07596692d0b9fb28392da808fa1b699d782c2528814a5e699cc114d0beb1387d48b123c5590ac4d8a1249b35c79b42f6734871941fbc002eabc416d5509f67911d737265d0936a50895b2d2ae883eac25d35989186823c10b18cae3427acb15721530270eb44a5162a8298a0af96a4d755e509407a99933a963bc090a441cd1344243b2d029a76a30886a8ea871ad73a12a4447189d418645d40c04b7f70ab1d1c252484c09350e6b4c05891c29b475cab1988563d982da3a2333cf39625308a9545625d4685a2091c250f03085da3a4d83dba3b605873aab28d13a9ad2465a4b84f6b92a515b076b60a1186a5b680817ad70ee6982132570cb4b57254f75c20a70c2b8c2196b9cd7681fd5565bcf63d5139715e40a0556ee789e72aa2cadd59947becc5b556612e5954c3b06e50d5200f61c9604f454af6951b8841a2b75911705ca0bebb5500ed1f19ec2aa16df51484925a474c4868594a84542adf45254100e48514a0ec4881aac67da7b8b73cfa0c6323e47d452d8a7ec600b0a79c7a1b6b9a3799909b42fab20676506d154803897123180da8432281892a756b2ca8b1cedcb1c047761d13ee52c932a476d2dd44e4259f46048a1908964f2d4acb065a63ddaa72a30ce09d4d63aada1548ceb5ba1b9f1055a07894f40c9beac7ae2c4205fa3b432bc447902f4ae60d9410fce75ae3285686a6529c465ca0ec682e5b24279228705aae0b146cb6c250b8df2c0e365a13862032b23cb954ea8092b616a29a2c395f45c57881aac11104919cea4c9a060750a51138a427dc892af41a5ce9d2f6def096c3e6a4f56c3ada8a927a3669ef18db65527e4c34f3e212b0bd8b7faba7176650de8ceef501a777c6ef4ea69d71a30f48b060f06d3f97cfdb58391abcd62b286578bc266673e999dbd18cb818884a6c1715bcf1db2bd3b0c04a039f6a5637d1872bbf7cd39015f9ebe1beb9359eb9ed7d345178504a33e8cfc077597048517566be97ad3855773a1e3762fde3d0b94af129ef25e522f1b80c5f2df410b6be9a3a5f2f4945f7a850823bffdf694ecb7e4260b03c29bb9d530a2817e7a1bbe3e0e9a0dc6ae399c1fc1ef5ffe722932f23805faf16a68f86d18fdb879b246fe0ec3f509b460c79d3b774879aa43c22f91dc646b17618c28464169e885a98b300fd46c363e8169198ffb44b587788c0c90045ee7df1e8296a79c2f327d231f6073fb95378c7f2527349e22bf5ebc7c33702fe21ba3f4faecf67b3a6d3bbdff155fc90f4e26e3772ee32df7bf60f7b1bcff9567453cffc13b21d7e73f3ffd3356cde1021ce016c19b60cfba5efabe05ae7c93d0814cdf652fbc1f585e6ec06e17ae02dfc2d06f9da96775f0ed5be9feec4df2bd37877f6e9baf9fb36719fff1626f789d9daef6be43196f8eff9cc7fbffe7ef7f654578277c1dff3ffd73e9f5a1c16079a52438c3f99eb99aadaca18bc0ca15be56573abc0f9598d41de4112838a67aec262bfd738be3fcacb4c2db1067a54db8c242bac58ce08511ac08c22858109d99e3127b763d291e50c6034891ae273d89c5d9a05313b71a0b0594b0d63fe59146b8c6ae86557ced54790549ed28dc168b464c1b7755a547c39dadb7281bf75379bc2d85b576c97f8cb2dd38bcc21d9f90c9b40def7483ca2faea8efe9d5aee5e79337eb1ef74af104ccc653841fa57bd236b9c9a1834ac7a8b1598cf195ffb8feca85fb01476a32bbea04bcfceebf5efee19f5e7ef79f2ffff0af2fbffbef97dffdfe2dd3c1e2663c1e76c4ad20fb5126a92ede1838bb3ed638e3ba4eb5276f33e232eddfb3f20dde26c02af6addac2bc904e35f6b8364797e9799395152b8b8c72f6aa4acb0b7b3f54b36585fd56ddb4835f6f520e6a4a9687636dfe9a6ea71705afa0dda5e9eec23dbdb7a9faf85cc25b0161cb9fe4fc8f700feffbfa62827cf25766c8d39d5cdca7411ea8e396ae862d1dea78baa9ab5fddd49df3aec7f5937ef87dba753b43f42af85d70bc1f02204b8e783ac5d81ad62d00e9425b16daaeecae1736c0afbb2c4353beaf979f41f17d43b2e590ab43756920fc60ac9681710960578a954b80b9182faf4373b1ff1c385734fce7ae86ae9febe7fab97efeff3cff0b9bae418400400000").unwrap()),
@@ -89,5 +94,16 @@ lazy_static! {
 ("@bendn/stockfish.gd/-/stockfish.gd-1.2.6.tgz", hex::decode("1f8b08000000000002ffed7dfb431b4792f0fe8afe8af670bb96d69290c06087047bb18d13ce1830e0cd6531ab8ca4119a2066e499513067f3bf7ff5ead768847136c97e77172558a37e5457bfaaebd535d37070119e472b7bbbcf77f68f77fef45b7c3a9dcec6c387aa2a1d3eab9d871bea4f6beb1b9d6e7763a303e53addceeac623f8fed3eff099e54598012abf4227e1a3ccf7ff90cfebdd13b5170fa2248f6ab5e7e9f43a8bcfc785aa0f1a6ab5b3baaafa51324c6ab5c328bb8cf33c4e1315e76a1c6551ff5a9d67615244c3a61a6551a4d2911a8cc3ec3c6aaa22556172ada651964385b45f84711227e72a540368a106258b3180c9d35171156611141eaa30cfd3411c023c354c07b3cb2829c202db1bc5932857f5621ca9e0586a040d6a641885935a9c28ccd359ea2a2ec6e9ac50599417593c40184d152783c96c8838e8ec497c194b0b589dba9dd700e82c871e209e4d75990ee3117e47d4ade9ac3f89f371530d6304dd9f15909863228d5f13fbb192662a8f26931a4088016feaabc58eca20ea531cd0428628c794ab717ae9f724ce6ba35996409311d519a63064d4e24fd1a0c0142c3e4a2793f40abb364893618c3dca376bb513c80afbe9cf11f5856735490b409551c00998da5995ac7c1c4e2630e93260d02e0c6fe87427c3e661cb24451c4ed434cda8bd7237dbd0fe773beaf8e0e5c9f7db473b6af7581d1e1dfc7df7c5ce0b156c1fc3efa0a9bedf3df9eee0ed89821247dbfb273fa883976a7bff07f56a77ff4553edfcd7e1d1cef1b13a38aaedbe3edcdbdd81b4ddfde77b6f5fecee7fab9e41bdfd0358babbb08001e8c981c20605d4eece31027bbd73f4fc3bf8b9fd6c776ff7e48766ede5eec93ec27c7970a4b6d5e1f6d1c9eef3b77bdb47eaf0edd1e1c1f10e34ff02c0eeefeebf3c8256765eefec9fb4a15548533b7f871feaf8bbedbd3d6caab6fd16b03f42fcd4f383c31f8e76bffdee447d77b0f76207129fed0066dbcff676b829e8d4f3bdedddd74df562fbf5f6b73b54eb00a01cd5b01863a7beff6e0793b0bd6df8fff9c9eec13e76e3f9c1fec911fc6c422f8f4e4cd5ef778f779a6afb68f71807e4e5d1c1eb660d87136a1c1010a8b7bfc35070a895372350047fbf3dde3100d58b9ded3d80758c95b18bba70bbf6a73f3eff4b3f5339ff07e328cfdbe7c3dffbfcef76d61f7657e7ceff47f4fdc7f9ff9b7fa20f059cf0b93a8a4670a82783a83698c051dc4bc2cb483dc745515b26320f47c12883136a5c14d37c7365e51cced159bf3d482f577e1a4faeaf4259423fe570562479a18e7f78fdec60ef586d6ea9609af4b3f71787fbcf8edebc0a6a52e0c5cecbedb77b273da0bcbb44a5b06496f4df5ff4936c652a9f95c7f2dfa17c568ef69fbd79f56cff485da9576f2edeab96eaa8ae817ab273f47a777f1b01f65e6f1fbd42720c804f836eab03874ed06975f1abbbb2da823f7cfc6b70a62b1f6e7fbfdf3b78f9f278e7846a7dac2df51554ee6e34d5da6a53751fc1dffa59b3b67485c92d4c6f61460b735a94756380edee3cdf29414bb8da63acb686ff74b1da43800a49988209dd87d8429f8b325cb7e9cc348d6511832e26bf77ca6fe84a520fcbaeeb8217772c081df939ccd4f6c9c9f6f357c79bea304d27bb49b19d65e13582a82dad769a6afeff55e8ce7c3297ad2de9a7cf5531a5b08a9aaf55aee2e64b95722daf4a099eade2e5d92af3587b55a4c4aa54596d568e4db90a945b87495f8761c7efd5aa2a4a21c8d2ff5803f3e17b3ef31736f42f76e74b07ed4ba7e64b17c0972e331ce72f5acfb533de2047db3f54ed0e80875b0a4b57fd75376ec95b37fd5e04625175acea0c9b5a00a2aabaae5aaa5e05a25cddad5a51bd0cc2ad5eaebaa0ba0b4257afaa7a4b750d4213b945a38fd5811eaa457f1da48ffc87ffe867fdb7b875a4b448725bb7ad8beaea2d41b8257d5f0862be7acbe96bcb19f94a107ef55669985aa5799f0361abb72a46b855b1ea3c105cbdb560725a0bd6bc01419bb875cbd4b66ed97400a566f880e3376f41ee01fee150ceedf031ec692adae72798e8013f018d1cf21310d6889f80628cf8099039e727687acc4f8fa09be1237c447cfafc047d18f0137002437efa0a00d21312a6113f41c3e7fc040d8ff9690d016ee023f2227d7e026c06fc04d80cf909b089f8091a1ef113347cce4fd0f0989fbe4280ebf8f81012fbfc04d80ce8691db019f2136013f113343ce22768f89c9fa0e1313fad23c087f8b801897d7e026c06fc04d80cf909b089f8091a1ef113347c4e4f8fa0e1313f7511e01a3e3ec649e1279c147ec249e1279c147ec249e1279c147ec249e1279a94557cfc0a27859f7052f80927859f7052f0a9dbc149e1279c147ec249e1279a942e3e767152f80927859f7052f80927859f7052f80927859f7052f8e92bcb5a1e7fb7fb5278ca29e40122c8580216c834426bc8224253c812423bc8f0ad9bbacf760d37ba7f70f47a7b8f6ad6969e6f1f9ebc3dda2100b5a567bbdff6901b66086a59adaabc88a66a1a5e25b5a59dc39e2dfe188a1f1e1dbc3e20267e0b17756de9d5f1ee8b1d28747cb28765d610e61b3f0d5682edd1d1c1c12b8dd61573974b1ff3f733d4836d39fbb11d422f4793f01c52b1276d17e84d7351ad71a9d6ab522dc3702f6cf6f12f6af6f1679bbda945c9ec527d043662ff550fcad3f723f9de90ef75f97e28df6bf2bd2adf5d65e01c6e1f1def1cf58e4f8e765167a47fee1d1c1efe00a56411ec014f4f02573f90a4efbfdb3dd9a1a42b9db4f3faf0e4074c6a751d0189253a5de6d53e69be302d09cc123bfeeee0d0078f13cc129e4e79f376678781bd37c050958529173ae5e5def6b773cb155af2166c3008fc250bcd364bab348882d23a853ecc2f5468797ea50282461c1a45496f1a66799421562f77f6db4974556f50def4dccd3bfc56e7d55086c6f41c75a95a593a8c0669161669962b5448436aa88eb7f715ea9693f35a8edae9811acd9201254da7d1b0978749fd32fd39da54c754aaa15a4fe471b3b6843864d139367e149def7c90e697200d05f5693c89ea41fdddbb079fdebd5b6e3c85a7a7f0ff27f9bbf709febbd778fa1f015529665982d0daf9ac4f6db6b3683a090700620bc566d4c1c33fd439d22613a6f03dcb595d3d4be2f7b36872ade2619414f1e85a8597fdf87c96ce7285f072af8be751d11bc63917c151917ebe60157e98913e1e6a6d2ae2b1e77b8eea894d152705cc18e18b099c55a45e469172f2348e066628752625d6b800631317a8fa8641ed702a2019f5b230b928a7a1a9c249a3da3daf1137439072934c176a4ba3345397a87de74e0385b1451055e9dd92864569d8b125a7594ad41d5a5a5631ae3104a8d720a2cdc3a08aeb69a44817242611ca03c04cd89aea2aba3f99109824e2190e874380e7cd9ab64a8493f3a89f8530bb49ca6616a809cd0b5a5beee0a8bffc85754bf7b69c71c054ecd6961d2e00b1e44ec903386cb0630818e7a38ef51a58857e59580daaba6427cead89b3666bd2afea9a34bd521347d2c1e489ea6cea112ed03ca6a20f715ec07e57797c194f608661d87195718fdd212684d02643f0c39ce0602e4d14ac00d845399bb1606b713d3e6bc29c09893b013cccb6a780198ea4ed80a0aa77b899281e02c889261a80575ef7cdef085ad9fc1583952ca684437e7dd94f27822ec32ae3bc009dd3ee1961944782033e1ae0841f03bf0544e7cc90b3200072e5529d38194519afc21e6e803a10d8c5b4d596c35d0e4509b65ed59cf1048e8b30c0217712bf81c471805d1044f09caa2d3925b69ce2b08d7b93f42aca9076fbd0618106a90b084f4cd33d5b90a8f2f7db47fb90bda97647eac7493a0827f17f47c31f9146e3f0f72768bbbd4e67ea7206272d0eaa2e2423c294420ca4d404ec7b59c064836cd768146de9bcdee3b5d9d3a3d8f4a92c9a5627299d8f08af699aa4946c16d1a81385974127b0a4c23d13521ba1a1d310e08fc8b769a6e94a3fafde48e1f79af25bb6ec54e3e84c1e8e2c4e0aefe21c309d4486f225300683700a08130917c0c2eee5efd5034f717c4a3d3ca3b5417b914b7e5362486941f77a40439dd1a6432f6f429da6699d721b35d937e9ac6f30c324c445faa37bba1023da4c84921d42c93344337f2f24ef36d47a1e6e4d666e35ffd5e0b306250519b508691a1e683f21398366cea33a70ae0fb9a52fe9c14fba075241fd45753e3c7ebc89124a3a62fad44fc36c485d804e1671328b6abfa43bc23ac216d4e4077b008de45181ddf0d4fba754f3ccf447b0432af11ed3aec648aa707dcb79c2f9709830404ab46b457a45a94bfd2c0a2f6a9f9d93d2721180bcb8015de1d31f10c52841763701b1e0781ae94d82954f8388ac26d1e380fa783b1ed0c8aa3f962e93ddf87cfd56b9fe1baf3e21ace9869e98d82ead49c44c72dee00546cfa731acf12a0257d7d996eb65eed43f29aa511666b46999cfa66636e7081f0a8244c8a84b2ccc10b5fb398d8796d801961f85c9c32f727401d2986a005bfc7d334fbbf0d0a9d3262e52e26558a4549f3ea9b9d46e430fdc94269884b12649694d91df9ab266ecaabe641eb93d9c4d27f1202ca23aadb3cbd3600a98a6c8a30438ca534a6d33be9fa4c346f43253d20e41a64986f54b678ff919e9cf2c5f1c4ea230c7a308a409c359e803898f22cd9d14517869b9a72be03906b0cac97c89ab8439a6c12ccb4026a12348ceb0aa95611f5d3984e6ccfedc34cba6d7ebcfe2c9b05cb7cdd3e824e064baf9384e5e0a084375674c49d0a291585ec63f352be2495c5cd3ca24071cca71972b4d784c0b91f0856f8b68ac9e3c510f4b0b9c18dfc535fea2baeba51a3f4703ec1a922ab7e2df6136d26cd55696843ab700622323473d2ac15bb5ccdb3485dd2555e75931380f669709e90ac2fe60188dcec7c12954697f102e214baf88fc16593d4b67b0981e0359c102d70dbbd105ca032c5d42c620b20a68d5e197c7162eeca3c5a63d8aa155a8088c00f4199b8711a284eed95cdf6dbf9d91f4c55bec8e0ca1741153642c2d8b6b876374063d0b1e3fda587fb8b6da0d4eb3b3399a965f85d31e9dadf54135df2b605961047b6c8024847545c48bd363092e9f6938834d795e3093b867815b4d47589688d3c90f873ba4bb42be150e7518171a16cbf1faabc49f2682f240da24aab2a085ddfd13024fbc022e8b45f0dd355e86ee0a15002a3a47693d847fecb624b51037421c2cb699c33888260869116dfb9c0a5ec0a8d009f111f958d2beb14a971e6f9cca8e1028923cd5252884d4a69e24a873354e9166d23ece555c50a94198171394e374731d6eaa43cd98dcb08f94261604a369cf7236ac1dc4c2424d239040c21cc4a24270fb5a5dc5ec4ef863b4f623ae1e9236c20b4c78f823811c879351cff0f81d84874997ecb9384b8a2863bddb6c3231c5ba584ca778c57abd718c63742d12438da97b2f4ee2a23e8a6860ca8e27ee213c49c3610fca615998629aba368c389c0cf5eeea633e8eb28831897e8eb2eb628ce32452124f755d3d5ef1fe03b9a4c57e2aaac1080de04403f9ce69d96baa53d9f6122f9045eba3b644732c335f5b3293589e61d8186622a5726dc99908d260d901478dc7921d5819d765759e42663f1c5c68a50fd09d703629703f911f2a77157a00c4a07290e76682e062366a4e5eee6865681b2416583358a5f1234d00f63457a31049907800c36c24a2461d85f104c40e72b445bd2be53be0f491efccb516f1b1f8d03dee91e1323adf367dc9e280f57c4fca5bf24128c1d8f3fcd65891f7c132a78f0de3755d4eacd4497203edcbb038bd3e3bfda0e51f2e466cfc7456d4999fffe8aa121c2d8216ba354d308ce337a4ab203a4e049e2c1988884703f5d9f6a1a9e8f0844fc3ac35c10f7fd18804af02ec9724eb258898eae7f695e1095db9806bbff982da6fe66a5f7cb676ff96b6df7f416daf6d7f3f398a068115254217cf68e84b89a8f60c5a320d559b512a68b2e86f4dc9d449e6544259538815ef157f0be86343d36e7fcbd2469be340a2cb6971ede8b7f9e4221e2ce094587259dc8d617d792a0f383c49ed018340c48d64b22d9500eeb494b901d2b03a4a3f28cd195a13b9240d3f60f68e327959723942a17a37e966dba4249342b439aa0a5146cd6bd06cad19882848c09125e2cde5b145e52d58e36ed7631c83862be0dfb17b5234c6d532af4772ea042b41ad561e8b18731ed7e4a12be68d8111497102b127b2d04fa9276780e6dc56a1fdc0f5b0b157b7567cb3b8e29b5245a23f7769f1e2d68ab7b4f81e2a0eb40c2d0f08889f68d6601bca529fdaa181ad89736477b8667da88a2593a6044e96e60dff9c2bffff40fd59b1b608986356810e440094469b0e4fd4b47c8f9cb9111b151815d213589d9736a4d5b536cd61e83d81d56c0db3da1da2c595cfacd6bc74acc00f7ba0701afdbab1e70a8dccc71b96dddf9e20c6aeda18051ce618f2693488473148e79a9b75f8a8a6a15d07af30239f0dd01f9955ce70e8c9e6762d84151d673916b8d471049c0a1ebc3f83a0efebb16153ba89fa97d6b2e2c685b574af1e60e7f994d086ab80fa6ed39010411be2fdcc42a01d3887229030d2ea72f124d53c3302b293515b6a38bcc5ced111482e7fdfdedb7dd17bb17db25dbb45c72db358c3ae93de244d5848b07aa3b6362990ce0ffa72af4e0ce6a933a96776b97ffaa4aab34565ec20b9bd77b4b3fde287dece7fed1e9f1cd78497059c503b74d7e5b400516caa0a0fd4b49a257bf04a73feccabcb8eb86d27c056b75bc731bc607b0bf788e2d3cbb56d6acbcf1cd2d558cb81ef5a708cd0422ac73b2a1babb48bc8ef6fca0e77f9ef656643aea03dad63a2fef64f11f299c1fc37572a7ada3e4f7da6559fd2b1a69a361669096fab477a16194ca714b0508b86506f256f18cd30f159d9543d1a5399104d446bd5cb8b6454f66059d2eb9c083f8e95a3e8859f5ad58b39aeb2171334bde6a69d9982cc1b51e26b34f518dda282655f07d3af2d6729102c69a648cf0c3031e60c2d12902b9c14695ab8353989adf74d150036803afa763b4fe751126561a10d8ae994af18e208de7cde3a38cbb56d515c3fc6d125a678baae59def8325ba2a8e0e22c2f7a55e4b61da27eafcbc526e1a2526329c1f6454797c25223eecc49741e4eb85b4f051ca66cc2124b27004d86a3cde5506ca1273a80f450d1f14b0240c96ced30b978a0695873cc2a91b380923dc0747394d5695eddb2ea4ed877e09dbac05035b05f32abd838cf199289d0b7b43e65398c7fb80d8bd0a0dbac3a29899ec8c8fb27a15f8b04683d916811922a347b4b4bfecc6cc9285a6144562c6a40acc4a3c1b9828f5e0496e746a3285d508e8ca9321a9e1b059e365b1293efca0758fb51cd3767ce3370f159c9c106cf25a4d2ce7983b2c38c06d3c2726acdd3fd92e701cca681d8730b37c43be3337673df701e976dbcb35c8ce6b758cd69096a6e82e6d3932097ca676653c5d634ea1aded8a05a6150afb24757a2cad6f4b2391d728c2d3d6ee02832b6bd4a74abf1ed79087b76f50ac3fa62cbfa9dbbf293e94aafca02ed2e16477a375dba27ab6daeb36d231a2319be7b97aded7d49fc911ce1cbc859b7c133859ae5d3c8b838dd6571db7db1a947fa560f002a7a6607df52f89807afec0850ed09b0c815c0f505b0f350bdb2be602b2cf130e71aa739d0761e857c585ceeda9637a57e479615fd6ae269769150a482341359c5756330f42948ec54d9c48ba03c4aaed06794e1f16853850df46684d1754e25b4c708ee50bcaf4b0c53c2a344db98a9d3b531c84562ac30f7735770bccb2ad312022a94fd03088a9a336d4bc42fd8b0426ef1772b8f8791e99d104fa31c416ab440a522ba2f29d973bd674d3bf3c5ac27ad57135d3e0c75106d34afa072b1ae59a75ccad00ca789f922f76081154538b88886180904587c8363e39642738ddfa9308834ace45e48604ce35ead4a67179ea9f7b3284aee3c556f7ea5a942bae30f41eb6ed3d4bac3346978772db8f61bcd6aebf799d5b20b9256ac13dd98e6d16c98b61cde1d03d6a0b195c3a888c33b9088b0c00ae995a116080966aa1fe9237d28962566fbad5e459c92a0f8289e1451469706e289d3a6232ef43cb9887cc9c599d8b893f77a6888b503205c3f8c20a26587116425e6ad2d5cc73f276277ae59324c1994a3fc746a902610d8889fa3acd02ee8347b78b841469a0de304643e8a84735c00938776d46de344beaf9dc89755fd781b58a16578fadb34ccc24b10da40328ac2e446e593743abd566fb59734ffc43b1542a9d93ffd2acd2e801347af1005886435cf1d19a9797f86bae144bdcce2e2bf89e5a45008fd308fda4a1d47c0ad4730899b8446d6b5210b563162c16ab2bed27dd69d1eaead3c843f0e5db0aa231774f78f545f472e58550f01c2c3b66ab7db6aff3c7a84167ac46972ede20422733f1a84daf5491f9209def0c3383e719244430710c329a2c1388907b0deaea91a2b356138b4a48dd3002bdcdc2a695a717a4e0cffc80b8d25a29b060632d29efdb9e7bbeb998c608d4e6199b28581d6b5a396587030491da872d03a0844b5305fedcd6dd5a4227334ba3a8b46f7aca32fa1e85f5f602d60c54d14191ba221d2d403f7c6886b137a50726f7770d05da8bb4c91fa34c7a46ad39083b8e3a1eca2600d10c6d9ac2162942d147c20a3d0c25a481c2bb0f4b5469b25985b01f4d4d72339a3c07724ec22b985e6c4498f18b5bae9b64eb92427c3729783e5a0e671ac4ed683608e20697ac4a51cedaa26719e01d01a11d00d6bd311f35159bfc0b25912f387b0cf40cccf668915f591d87cb1a4afef94b0314fdb48327595a514f06ba22f7c94eda9c839fa764c5cf8dcd1720b8ea1d4737b70140bbc5146122386e419a05e4668a6214a86d107cc71ca3da06bab8ca1c41139a572c8ead4bbea9b6fe42aab2b3d994530af94d8d4bc8bd386319bda2abe41564b05ae61bc24f0ccd724abe2a29a9e3c6ceec588ab351abbac14131a39e60ba518cf8c2fa44a444fbcdeb7fd831e499df913cf8af64f3395fa931417b9d5321a29f4275c12ae286dd6d14f46a6d79ee20264cb0e8223fffde488afd2d57b5263be33bea38cd98c3ed341f3d0705d6b4d96efb448e534ebc64615a3f2f7c88a01e437848973150cd5d1deaf0610aa38e68ec6469b5cb44809da7160e5453889cab0eefd3260f96c348a077194143d8498c5e1a4ded82cfb6190febac337be3b7c0dbcc3d7c0e58a387c4dc5058cd6469c8fd3a9a73f4f66973d0b50df6e7cdf33d7763a5f4c116ba8e5e869d347dd3c9327c49f4122b92b29fc8cd673d3ba6ab8f44493e5f91dc826295ea232149ab5d548d279ef8c09c302d15bfd9cab0b3ae39c5c80b9eab0ecbcde897ff10bad59ed6a7eca5810e5ee1a82909f8a9d8c921b7c2d091b6d03714126af8dd485be25b57f87764b2d3e606c01705f60a82bbabc88d13ba1661fa58c91592574e510ef2f3bb7fdf41144ab6476290b4404918468951e595ed76220ec237b2d393401581906b72f77a6e0176e001c0f794670e4b3cb3a1d3407243e0e9f2733b0f78a711645a37432ec65d1342ac8e98976d2b23a397871b0a94443e7de681ec549a49548336082294468082c79816ea778025c4e61a79b90a557e96c32a48b0aa1fa47dacfe2bc5017d1b5aac33e2ea2901881973bfb8d36df875cf60a71659007d1951823a772104e1c71877d5a71581cc2136d84cb2a440b2f6ab3c8b72b0521962f0988e87985fa2c965dda0bec67da114c0c6efa52b91e2ad758e5eb3235b412135162c5e86ca06235e708a9902acbe0b5d72df50c7562aab84a6166a2c930d7e363dddb9af8fb1af577e85981f795a3210181f59df00ac251c2391d66e195ea5f631f053ddb5b232488e7300679622e916c9075fe97bce5daf9741217f540050d78c690b07520b66bf0e3a7344e285dd885b15cdbd523add20179e16150560aa48be388ceabf125ebf7cd949c424b74bfc64f003a8adb0171442f14338164823394cfaff30488903e9bcddc6e197643cf92deb5de6c95b9f8bc3d4da73d7404ae371cf1df02b6a7fc74968f7982371d97623df3184643bb05c875fca6719c5072bcd18f769fedb3fce38afd56c52355dbd63dffe7bea3b1d495ad4b29d7779d38adfd00733c774cc73b8b6c028e33a679c69c1bb47c9a9efb43e6dd27dafc52cbb53b8ec6b946e4b83373361a71f0cc2b4229da6f457c41a2a9d63e35dd6d66ecf51cf5a5523af4cdfc1e2ab030ebadee063630cb0d532dcb72a35146c27748414428dc04456c862d7c25ce31771052e70644bb1cf9c2aa753b9ae5371a8b2bd6ed0f1d059d618b35c405fe48a542c6c547c79cd0c2dc952881f1fab519eb2c4d2fa0971f4c5c67911beea43259a4a3d71321c6ed855a643b6154ae4a215fa5d53dab55eb8f1db7a83b2a6f3ed7810777ec408bb8c95faf031c8b819407a391abbaf7d4f6c4a95715741614b00238c5b5b2ce5f7b4cd1fc03f9a64048c6c663068ffc84b6a894767f0016b2643ca05c1c67a6ec5eee3fb76c76cd11df16632dbbbf1271a44d0b50375906798ee2b108752a7f0bf29cbf007dd9b4fdf89cc9076bea66d321c848a27629dd49f27df5ebf3a48a4f9132b12247eff9756c620e39cefb6c1b40411d1158ef28f792128760215ce35c280da9098cf64013ddd225807a4755aa0395e8f6efac596454eb0e7094c3e8a68a39c950ca5930161d73f3c33b973c39dae3f92a7de280ff26089a01b00c04ad33cab60a08ed87acfdb02ad84ca8d1e6fb0f9a59c014be14a711c6143e5e1dce0013edbe76d706e698dfa5d9c03c9be0dfc8c03cf3bbf60567bb8c61d561ed1f2df345dade0147eb432d7310a6a1bc8c419f7a7905c3c0c4ba72852f3adce78e55bd6eeda98a9dbc59a8bfffd71807dd346e81728bb505da762fd4d7a70ab39e16639c83a35671e8dcf954764db17367996fc52c9fd5773d37ab9b90637051130f5829f325a7e4ed87a4efc7596de9bbc5aac7363d5cae6573a067a942308ead4acc7b5a209d2734cb1c378dce36dc02848b445d238b848adae76a7fb4f6e0e93db4afa5206fe14fb91c8336c59e76de9d8bc0d6909dcdc6ba9e6facb3ba56a3b2b231ca744832c9b41ed844dac202e3a8333ba1a318f0ddc39c75cbab2d0ac488f7ad2336ed4ac839eb00d610ab9764d049e1440114ef11ba1580260ac756cab7255122f880211e62d41f641105a022019a5d54a8aca7d925d28c404f4ab0323677b3c43bc3dfe64e669226ad5c2f0a0a1ecf208cc5a80dd0d0ba29ddcbd1ffb305bdac83b4ce1747ae45d5c115ef7f94a637498e5537f7159cc2055d04411b2e42d07d0e477818d33b4b040586b170710a1628bce3edd268d8a60a5ceb7bd42a00d844ab5ebc2190d069dc2e86d48b3ee0289c6761df1a0e9a0c894258a42bb463b4ff14bd1e47fbc2e25b578cd736ab337998182e5eb8d66e8238c8b0a82fe9f52ece58eb015693f802ced1c368b5156160c94177005f6f466b1f468f049dd1a3d1e3f718bd74b0e67477ffe06467531da79791a36d402cf908bc0caf517f65a3fd91d265967b93e58d505bbd84751c7d08518fd664750ecebc009705bb71d1a5ebd4cf1ec9bdea67fb0f5f75edddeaa6dd3188b0c62346be6b0a0b3592398629dc876c68f11916ab8765533ba9250950a3ad766983e424b3d19dadca1ef04d777d015a44b8cb342ff89f7e98c7038b890e880750ea57e37830c6b5493823341aa714716cb48d19872820cf7139bc232b4c38d389f378eabe75e1acf1b47e1ab6c667a7ddd6e3b3c687a72def77fdf47dd64fde1c3ddb3f6b184515ee652af3940a3dd5affbd1764cc686b489b02f92e1c4f77381e167383007952e0d7520bfd6eb01760f2aa773e4e7702db94b96e1d032e2e87ab495237196176b1b8bd2a98a1ee959236e1a75b5e2fd30939903fcefe30ebb0f89598c81d04ca0829023d6a599c4b143f5a1be6ed736da40a06974ef7d7e2674d667e741c6f4d699782a53c12ad81c57d6e696b312da7914668371dd1e550d1d0d914a33899623054e6b4e6db38a34b7f7469135e042daa959330c92bacaa9cc6348da1aa7b9574874d6c333df3c4f6ec8b603668c2ad0377ec86e0f9c2ef42afab0a8130b7a51d98d45fdd031221112c5688487f6244ace8b31dbe8e4fe6d251b60f5a9f3d103e7220ffa93e8e9e6e78c84d824694a975caf9ca637a322507a57326d73541698f3256d9099f30fe34117f2c69df4420bcbd4705ca83287b4d0b7a829cc5ac318de1d3e0b86f3b264736799ae1ccc6d699e9f619e9c274ec35826b2d4c2ad687c76619ea7c09a62d04e522c3221c33595f32ca7b34c9376da64d625baae3d9febf7784cadf5de5c9da13e18214c7b3b2fe908a7a5cb9d261396e4a22ce7268b70dd5e78dbaa72e2416b62dd9a3280b859e688bcebb633d7019da93bd1309edf7373c3e24ad51e307596d5f1e1cef3dded3d05d20b700f57c2d60eb590207109f4d10153874ab3c4d6c70302ed3844a15728f6a7efbb65cf133800e8f4622a6f41cc917b72890b85339877daaff2add225dd05f10b56c46db37ea7e9a4f9d47a4146f9b47386189412bb675ea3ffd21a701681bf0a1c39605e669b0de23afc2dbe8f8ef1fca204cb34f0c6cf3a915a93f2644b3dd4a2b9550d3b0304a5daf45ac20c2fbfac36b4c792ab21ae2edef18acb954be76e84eb5c25572197bc62a2c270cbb1428642b8d175718adee65d04d607ca03b54a776338b2b10fd80f4f62ebb46ea9e30744b9052341c8f38364ff9d5c742ae65a8b0b812d1a62dda46b46f39762048c7128928e4a01c25cbf03b3e22e8fd71dab21b23ac0db2f09c3ccc2a1addce5b485cb892f23068e71d25ba8689a43b6bcb8aecfce81827d3ee29f7bafd754f1e322520a9c7ea4e79c3f0d3fe760ab2110b352e1adb9e4d805aae954cd59d4da0117e78a5e48003385632c8a027c3cc57454c45bad93b3dc1f6c71287e2e55abb9d78be592f29c1ae8afbfe607c0a9173bcfde7efb2d062b787b822fcddcdd39fe755b5991e01251362aeac3685a8ccbf1106fe7cbcc1c12937323978c9374e87953791190ff45ef7ce328c76e9188326a10ad57243538c62d63d070ba676f4f702e4cb353d8e7e24d89ee62977faef6ebce3dcefce1db677bbbcfd5f6e1eeaf0bdafa05a11b88eba7e7a8fbe1fc59274f24dfa78f532a1df3a470b5af916ef41c43ac23cfe4368b2c45b9694862788e73a2497310d2699528990a0b905a92d86b14b55edc53f2187925e78689044eabba98cf1e546351b7a09a0ac0a3194c3b4551603652f3c96a27b71d52f8c20e004449d1556305a0ba8cd064c71763c6b3cb306965513844de4d58bb01ac34d13d88b699d14680a4fa6526ae74b5c4be96c0c90088bb782d274f811b9a25189b31a909c3a9351284f245749d8bb040de4324142a68bb9f927e6832696b7b8f3e13aae9840c5da3c2070ba981a98d236441b11bd4308d1c6dea150e53488e27f262070e685327b5132af826d738a00dbad5f8d32c2f08085e02e14bfe82bd77cd1fb8447d695eb2aba275541e9b0d73bd36d002e122d03a7f1ef62da2a469a879872b2a226a36dc0bfce54efc32d88df960d1cb3017192a805bf179920247a3a380f6607d8e802f28f29e963f9cb06ca15a1da2dc9ac51fbcfb01b2d5e8d978fadaeb34a77e985bfdf38bee292c0ac30630f500619a377c4e1e1d0a1fbd5bcc2e2712372498a058eabcd06b4d2dd3b3ddcebfa9c099a89c586a2c0c9bc643a17101b41a821ec7a774629e55defe989e9723dc2d5b0db50e4b359037d0ebf7de6eaeac5c5d5db589880d26f2f6db713499ae1c7ebbdfc24a08669a4ef56b7e6cd0cc14235e65183014445e9daa9d2bfd543d9f3c85f7ac919c2e81d425d6925fc72e68e75876c9cb66d97751d945437b40c46f8905b7ac88cd66674bf44c4d4772022891d77585499a5ee4624908d6daf8b2b56823d0d8cfe129ceedc29e2c30e6cfd572dc036aee8d0bed4189ef7046851b6974fa1309492a6642ccc39731716e97aec8196eaa84973662d88b20413f307443f7798be2ba01180ce6664dfdaed5d6d6bf0a5c67180161558886519bf38195a28dcad64b4dd7fc2278114a62cdfd42d9a38201ad2dea4355dc768b3d2e26bb0ff2b1766fd68163af50e1131714aabd0f8c094748d133ea5cd08729241f6738a6d1a516ea82443b8e327df9d5a5c7aed3ad13ec15773dfcd9ab5ef3c18574b8387b4b575b2dc5e4e8daf71ceb6ce5c5d2a52fb9592a572dc482590af7230dd2b94a8ff3017fb45bf6c230b6f6f56412c61612787e0e678513be58bca9690ae84d3f3a36e89284f2d5a265947882257aafdb00aa63e0d7601733d3c23efb3888910ca8b91c4d9c1440aa0bc379cdfb946fb81520aaa20947ce0168a1a4a60aa02a6f3084b1556afc142a70083cdf5827ab10a428a4a988172e0fe4a3d0c214c2c2c060c238da71713fd7b678b974876f22c08943462cb95fe83bdec8dda6198cd0344da853a7c751f176aa826e7046fd00e992ae363026644c88121df6a11c8b7871cc3f7316b37b03f07ad2a99609685c3d7d78438fb41343747747ec665375bf7bff8c2313013ea7e840af8bcbcd9a807a11cc4f2cd5280db714267602faad09dfbd7a0090ab60003b687a5e868555cee4151e9f8980e886c7736226db4bf126069bb9d0a08d5178da8904ee09e0e2835ee17aa23d4fe66e332c130d3336e830d1973e51d2031e2fbb883219918ab7d473a8487ce505debd6b7575bf0951d2c7fae2f8c2f5e1be2ecbfa21cea342fe8421dd8308e56eb8d6e89b568380df9d33a78c009a1151f8345a598fdbabed0dcdbee2518bb492cdae470c0a37c0348c337ed714d9691008db98f8bd1dd9258a63088ef16bab03f4d618a66cc925ebaf597b195a7248ba6c32b75238961c6a0ddd2be33cd71edc9283d119bd75a64f7e0c1ee1e59c068c7a703677be31ac861f6d92a55f731f07e844c2511d2613cd99086105e11b28186d4d0c60101544263194002dd9fafdfd0ffd47f71b8a3edfb4e44ed67dccbbcf3e4048835ad079dca9d09ef3d64803235a8d1ede0a6336884d2553eda3e8ccef8f1fdda737cf6cde1f3fbeef044084acf7f7e1a4b430b1472e5c2d855a55c15dfdb50c2f0ab5ad6f997d83032dbdf24b2234e7c3756ef31473f72b16b76cdd7c5db458c84a77df21e163f062f7396ee0eda31f363f6bb115c615444c6421649fcdbfe3d15a953cb76f47bfeb18a1fef217d7bfda2da3639d90b9c77d930c3546eaa972fc477a53a467ee6958be5446e8b2e479cd8e50b84c907649afca43ec7a88d95747d24b20f0c4841521b285097141bee778b2ea31a4056e3cb6886e8806e91276aaf6a3434584f1d7f3d5131a1f9c853231d3e93a02ab85537aab89e6a3f5da98b310b8af35a97e8d0f2f7c6754aa14f720eaceebfbe7222d583ee04b41698e1825c5faeda68dea380af3636bbce39dc8cffc5a9b7c10c715a1e4d92201a4e9416bc1e7c1bbe4cb82ca13ab3d9d84d7e6bd9335fb7a4f3604b1a4c5d1c055507a390e07eca357e6a84f016f570ebb20fe9b0bf52502b0ad02f7f0fd15c3cfd388f13b3437ff8510f47edf0520763758a865e1f29f682ee6a3c86b709f9d46530eb507aaafd440a9a152915223a5ce951a077603965fbc26afa163177989cecc7b6c511850b9b1ce2f065c1818db6c9c6082ee6314e4bd2eaf40a4bab87b68f1e8df74599daf25b34171087c4a606d1f745ef1ba1771b7ae75c3dea1e7c6af5da4fc316761e97536b20b7a0b142a77d40bfd76ea195a4865a5f13c367304644e475b51e7cbf5188b14198eaac0e8e2fef47ff23385690bcfa31510c0dae7c3dfa68d4ea7b3f1f0a1aa4a874ff7d1ea86fad3dafa46a7dbddd8e840b94eb7b3b6be0adfbfc700cc504e06547e854ec24799efff219fe84341effc3e8a249e4e6d3009f3bc97a09c081238bfc1ace2a5f292dccbb3019de5ffac3ffd86c34b3cf13d5ed1abf563b7f9f866e569e3e3e39bc6bb77f903288b1bf049ffd395feadaf963c697d7af5f4cdd38ba7ef9fea2cf3a61cc8634fd9b5d6c699ced5afc579f2eeddf0814ed49a514e0cfcd780b9a1f0f105f7da5557ba631824f3aea7db5fa491896fedb9f667356f87ca98a9977b1d563f71aa3d8e32bc19417eb9daa1f5149f71f025b2767ea6030bac040d73dff28a2e7b4065c3693095ec415aa919aa0187a7d4307e39f82e42482665830ea084457bce8b547519e3f4655bd16439081ae5604af38510867894ea245b487bbbc2a8b1944126a500ca069b386c640d0a70b9c0efea91c2cc332ea797d1c2b2ba8094376b6b6105fbf226aea1d75b40bc45bdba922e74c646b040afc75b2be942e4af77e3bc528c46c632fc78f9bf176519f043413f243522cd8295b4be9cfe4fcfff5df47f757da382fe3feafe41ffffedf4fff05ba1ffb82a0b2cfa01c36608b9ac2d05f5d3fd67af8ede9c3d75af349cbe7bd7fa204994527fbab9f5f4948e047d07e2d3a95c8778fa375bee53abf5e91f9d4f387e9ffe069f4f07ad03a8db3a80f29d56071f3b8da78d4ff577ef3eb6ff0adf5fd3bfefdefdc769a7f5d5d9037aaed3bf0dfaf7af9fba2d80d7ea7eeabe7bb7b2daa27f2107307c7a0f0fa5d51b381bf82c2bc273af77f7fff9eedd69fd74bbf58fb0f5df00be77c6870bf41972b2b3bf368277efce20e5afff71df40c051f3a004fff420bc7bf78fa0fce6575d144e1eef9ca1c3d61c3155f77efc0b3f7472d9d82be8b9ee9d639eddab10a74013b5b3747112bd0ca80cbda9092f4899772cd5c458a0f5dca64c88c78ec4a08d133a14393e0ed21639c4401ad46669adca15ad3c2ad9c4e590de72a5f36b3a5add3d02ea1ac14d53f81a07cc3ced9cb157490f5fac90d7f56d18aa8afe2ff88d4ed2f44af33fa367eed781446b67e828c98cb23429b8ae1fe90fdd4a0500beb2ef5422f5d950e695306469f458d5bdc50b4db30a584363690ab9219fa75c8333bc5b2f5c83579cb949134ed1dd5b0e626d49e1d4338a7833d5b7609c037b19c683f5ff76ccbdab9d5e71891be19285aad0488ddac2792b4f9b3b6273f3a7a7cf8b40e4ccc4fc543a1745c8dd95ccb1a264652226c3d50b27139e01e3958d65cdc9dc3db31a50aca879977229bbed3e06328270cecb132046bb0499198173f3ff91aca9cf7f90810717a318180bb4c745d9afc90cdc7efe3fda58ed96cfffb5eeeafa1fe7ffbffdfc3fd68b628fd684e74a61168cd6eeca4f56cda10b679cf7f2d914ef8c46ecc1e631ae8793b040bf4f7ef59d2ee7f2b12628c5c131f0d3796f14851860a21efc67f873783c00325104869e8c8098bc0472636e628eda296cd67a8032d8ca4a381ca6496e57392cef15ec46fba71c480755c437d661450bbd1d0155ac8f508ddf83f671eb925315c01e4cd29c3d6e05d9ff3c3623608fe739b5a03f24386ee8e6b179e75e6281ab30bfecf56718ffb61716e9653c6065ba7637544037d5c77854679b95fa3eea6fe77974d99f5cdfdbdabacffea1f71b6e78c4af8164e6300be92c1b445b6fe13c7f4c449c8c08f5d34ef3ab47cd6e77bdd9ed7cd5ec363bf45fb709bf9b5f6dd0637775adb9d65ca5cc47cdc7f0fd102a6c40852e57ec6e7025f81fab75d7e0c7c63afcb3babed6ec3ef29ee9ebf1061603dafa75654fdafae2167649f7fcfea74ff7aacad4b9630dbfcf16ee368fe2c2d1b1258fc761160d696c9ed10c78cdfbb59c6094375fe3a404ce6a29adb105f3da988b62499bd3db6cf31b98cf6634f46fb273114641408e0abe7a98ac5f7d9614bd3847b7eaeb52c08a28398753b1579585e6ecdefb59348b161cfa0c1a8f57bca7d08ff2421b93b47f521e9fa37bb8db88499c70d2208e7ec63b793a955c44c8d868d234648a5b846602542760f5fae072e831d2a2e9d164c96d5742a8e82e197dc5e5b0615d70e069d83fef4d016091d7d1f3af05500d2941273ba9d0f39060d7aabfa12358160f23c1b3f7794451dfa0bb35afb142fe2681455a0fbcc1023296479311b01b3d3fbde156713befd4f0921bba6da7d76157bff9305ce5a7f2d002f902ea368cfab373bea2253cba0180550d68af45bf7bdee233f1a639faea84d45876c2d846650734646f65674681fca349a3d3b0eb8437411da874cfd9241e0ee425b3a574919adb48301bc0d715a6d3d8f6b4c354ddf6ce26999042e9e5254a4d159be634d0c5918b25b72d4808ce84fbc586dac6de231c2e01333a386630cd653c6d3282a1aaa85cae4dbd6fbbc66d6d7911c38cd77b5dd9f857da4e7bcb8ef8ea45db10f3dafd08e619c85e5c8ceb419c8c52c5e79cb7d9ea669b356070b4b4442e191544866e8dcec136d902bfa2a2131a9d84659343ddb0a15d9b7ccf5e751b72ab9630a9bba4943c89ea2cdec03ea1b4f48222bdcfa36608b93ac5818d8b4d1c51bdaa2d7976f08ba0548f6960693b7b263497d59a25d187296c7f0c35474ef09be21bcce329f357ea7898e1bb502be68f6fe15e44090af858ca897ccbd32bb91cc4a3909f4656ab2729b48a4b7dffedde5e604205d252a5a64d05e72d767dbce64845d86d415ef7ee8c85c61c20f7c541c6d0ef4505f97a830cc079ea5cf983d6baebe5753bbf72e6c63a9cf08c99a26d7a19c4753aa32b4dea4758d0d37ae3c7a7817fc2542e4aa17d9fa51fe729538b325da0ee04153978f59932899dbdcbee66b4377d4a8889a4cf62b6c4e1811dc6c4a40963021c5968c8446fe0845271b8a2018c621199dcba1c545edd40b80d873bf842704e4d7bea559cb8a5bd28d3e2c0470981eebd8dc2018ccb550c6bf42a40658696aa3dcc0146f5287c2954e935392f6f550e44edee6c47992995066d73cf7975d4efff39bfdf306c0f32393fe5ca8c3c908419864267274dd8f2a417ccdb2a56e733e44663c8ca530ef480fb021d25e968d78cb444e69860612430a86a24cfc96bbaeef654b327ee10d6f19798be7c76c2ddfa658e092b219dd17144699a28b810d42a3096215e4cc0581148c8d0bd5fda7686badebb5bd3de5a13be088806e72893f36fd2ff8830fcefb0ff77d71eced97fd631ed0ffdcf6fff61c97f38d9aacfb249334d5e02f5cbc72fd2ab04574463ebc9c751540cc698db68e33eacf30d873cda7aa29fdaa11588eb0d29869b62eb49192025371a375f2fa02d5b9a06e8a3a8f1b15cb20dcc71f11aafe59e9b030b007247b00d73e06c85f9353336e1650e3dd18e925721ec6a53aacef9064419e52dee096a54ee11f67318b964b8de10c1ffc643a5fe11c5fb6731195810ca8d0c93d5a3411365c05b36776ec0dae17028a3b007fc3afa4111d7b9f5640e3d9f5012d3d780f6076852a84715ed963a548cd1e320ba81211a02256baf4ce2bea34ac39e05f34be7ebffa31e55ff33fdbfe41b8e8034f99de97fe7d19cfebfbbfef00ffdffeff2f958532a40657f00a2e7dffac024269e963c686201f4f744fd0494e9e2551f4e1d4639b18b92739e0e51956ff46254065f62839915f6252ec020d03c86a8404201f20fd68806e354bd0b7650aeda04fe4b6186be991d0ddf052868471f80947703a87943d0b28874296976ed00c49047885f5c50939004a799a43cc07bdd682180e7b1dce8c65168f9c3d0c6caa6958be81a78c121227dca00a9ef1a3afd68b16caed3fc618104ba448e20cf0864382bc6698648d11cf0d8609c25385f31f5f5ee09a7e10552db37e9c8dd3ab112e73970e0b61fe3f4329ac2aebf3b8865948160b1d46efe209dffabe83f5abf5eefb42f7f0b17b0dbe97f0788fe3cfffff00fffafdfe5b36c0976adb6bcacde224359abfdf8e38fe74326cd35adc8d94f8711bb839186a76c1d6e3b8a1ef1b69dd3ee2ba515361105c02a8360cba9a20644a101a9657bb314688b969e15d8ba2a3b9da0b86ecab4bd484b4837392badaf37e8272918addaed1a5f2a56c7324d65b5855c542ba61d256210a30dd9b6463a4c9ddfc010700ddba86b2d1068755b95a29200c4778907915e30d6c049f983a7fee3f3c7e78fcfaff6f97f37e3693c00ca0000").unwrap()),
 ("@bendn/test/-/test-2.0.10.tgz", hex::decode("1f8b08000000000002ffed584b6fdb300cced9bf825081220112c5ce6b4080bd0e03b6c3861d766bb754b11947ab230b92dc2628badf3e4a4e9316edb01dda14c5fc1dac846fd1262d5a8bf45ce4d85f09a9789eb59e02711c4f462378884e48069357d01a8e2771924c2631c9c5493c1e4c686d1d009575c250288fb04902ecd6178223c8b3b490205406f6526a840c1da6ae34200c8254949ea2c0ac0b6e891b482b6350b962039545480b61ed4c891576615e39d08674c95010cd4ae57874210c68780d4529b2363368a7fdbec88867fbc171ffab30160d3d7aacc3155eb63b7004e952a81cc1957076476336cbf56a96a1b6fd840ff878af7c16a524e0c05673724671d4fe789f08c136593d47d4202c48bb17b64ebacae16d9d3b1e1d5ae76d0c6e8cec43e337f4285a542a859941916d28fede1bb82865368d80a039599a0993572bca5bfb612f7526de9b7c9f86ab7f4b43ad5927a3d63f0b6e3d9c91798ec6d2e64e58ef23eb02ebbd0dd765b8f6965868f6bdbb53f0ff4998d9657949f750da9ab2426ba945844704d7d2b1bd86489d2c55d0a1270667ce54c802f7bad309abbfffb47b1f84e6dadfae5d326cbb16918b20c173746d1662eac24214163bd39d236da4cf1ef7ec36996e35781ce86dffafeba4f50cfd9fde00a37bfd7f38899bfe7f08e0da776c0b5fca0c9b6af8afeb7ff03ce7bf07eb7f341a34f5dfd47f8343d5ff76e53f6da90e5cffe3e4defc978cc74953ff87c0151db1999fe0d814d8bb39f50215869e70c86717343ed001dff3063ce6495c9333b4a991da6d595e5eaadc8f7d40930918a431d1eb412ab498cb423a89b656f5df19bccef67b434dacad59a25f85137fb0e8a5305d9670ca3e18539a29a8123c03acc6542e2466a70c8e8fc34c02891f3bae83b573dc5c9626f3e64e6e99a39f61d661a272cbd278f361bb7508854c51d99085cf9fbedd6c539300aad447bf8b6d9ba4307979f1307c05efd175f462ebdf0faf2be4aba73801fca5fe93787caffee3e1b0a9ffc37cff5125954714f5e047c2d77c0d76a39c587b32d84aebd238cc88fb2be1c99fb8cd6bb44183060d5e1c7e032564c4e5001a0000").unwrap()),
 ("@bendn/gdcli/-/gdcli-1.2.5.tgz", hex::decode("1f8b08000000000002ffed5a7d6fe2c819df7fe34f31e7684f660f1cc8eea615bab4651372a14d2002727bab2ce21c33802fc6461e7b59baca77efef9917db24243d55bd3db58715057be6797f66e679b1979e7fe7cdf8c145e7a4dd1db45ffc1657bd5e3f7af3866d1bc775587f73c45ebc7e7b546f348e8eea80ab37ea87477fc2ef8baf706522f51288f25f501217cb7fff47aecbce905d043e8f04b7ac9378b94e82d93c658e5f6187f5c34376cba3496459573c5904420471c402c1e63ce1b76b364bbc28e5932a9b269cb378cafcb997cc7895a531f3a2355bf2440021be4dbd200aa219f3980f0e1620d339c888789aaebc840378c23c21623ff0408f4d623f5bf028f552e2370d422e9893ce39b3071ac3ae482613ee855610319a33536c15a4f3384b59c2459a043ed1a8b220f2c36c423298e93058049a03a14bb58505a29980062467952de24930a55f2ed55a66b76120e655360988f46d966250d0a0b45f95f43888132678185aa010406ea96b219d8421d19764d0549b48d0c86a1e2f3635098435cd92082cb9c499c43099e4f80bf7531a21f0691c86f18a54f3e368129046a26959434c79b7f1272e75515e8de214a22a11c801cbc2ab7a4accbd3084d3b5c1c017e6f54aea24c41e5b264a032f64cb3891fc1eaae982ff799b0d7a67c3f7ad7e9b7506ecaadffbb173da3e65766b8067bbcade7786e7bdeb210344bfd51d7e60bd33d6ea7e60ffe8744fabacfdd355bf3d18b05edfea5c5e5d74da18eb744f2eae4f3bdd1fd83be0757b58ba1d2c60101df61831d4a43aed0111bb6cf74fcef1d87ad7b9e80c3f54adb3ceb04b34cf7a7dd66257adfeb073727dd1eab3abebfe556fd006fb5390ed76ba677d70695fb6bb43175c31c6da3fe2810dce5b1717c4ca6a5d43fa3ec9c74e7a571ffa9d1fce87ecbc7771dac6e0bb36246bbdbb682b5650eae4a2d5b9acb2d3d665eb87b6c4ea814adf2230251d7b7fdea621e2d7c2dfc9b0d3eb921a27bdeeb08fc72ab4ec0f73d4f79d41bbca5afdce800c72d6ef5d562d3227307a9208f0ba6d45854ccd363c02107abe1eb47382ecb4ddba00ad0121938a06d8b55eecaeffd36ba9e37f2b99b9b3c98bdf21febf46dc7f14ff5fcb50ba8bffbff9c53fa788f082f5f914413df2b9e58708c5e3c85b70864561599fbc8445630476c19ac7ac2e9ff1344ed74b4e23b6cdd83ef3bd88c2c62b398de0389b21b2d1f4cd480ecd79b854d039016221ca633256cb0191c608f2727482305e029af0a9978529930316e263e42343882663cdd349e3313d37d90023d1acc26a7fd1b74d6b6f8af89552483312626c2f40ec62c7c7cc6062682fe129222f4b2d7357701b2397491dd299229e916735e71173a2f8008194f128ce66f30afbe4851977448551349f059f000234a5b78e983084cc7504e5284c484111d293859756291591019800a5018128b05badbd78a9e22e0cf325b73619bb6a7c055755151299aaaaec686e0b1b564baed0cfca0db917ee2d69c34f7140a6d114c17f3ce3a9a3e5a8329b26806ddb156b2f27f1184c4d11a0a20ee892fc8fe1cd24306e4600266761e1c553e731acd21c90f54a851c3afc70d51e0f8614cac9a9f9a2ddc627c7950a141ac0ef7b3c149c08e4967d96b5b5a74dfd188a26b42ba4c99d8a8436ded88620e772bb965cb5c5b46616e09819e7cfc4462fde1256939daaecd84b90e2def1b5d93210705a69e62b1fa037981d31981ebfb41e31c4c8280034841f30043aedd664639393f3687b1847904d694706445359506d3e8df51db008f8a5c0ef4b16149e2843b8ae6be7c2ea09239558207725c2cee63960b6b75e5cee22889cdc46611ccd7e0d8ef7b9c029b9741b52490e37e1cbd0f3b963d7b45bcd292637bd39c7c47642366cf15290356eaee23854d3ad24f1d68e41acb8bfc4508788a34ab26f5e8a11c1176b075e281ea41b6d7bb44bf1fed8f9df9597089efc3629e0f3f9dfd161a3fe30ff6bd40f77fd9fdf3fff538b42a58029b576d23c0f445aa7cf2d6f32199b2cc6c14d93d2460a00250cd75b2ec186a6f3f36e49c473cc2266f88b491844dcc45a75b8f5062ed12acfc9e0a97349950865d1dc8b2621722692ef5ecd0634556ba807f2b50973d6de6a1e841c00dfb390474e9936ce5ed66852b236a3264b42e99cccc23490e449a9234520d0568267092c4881bc4cea2618e9f94405c2f158702ff1e7e3927d1c8d5b51e928202573756160456d9e8cba64c499622469c24cf20d789e4af96898a61511b71c69f7f6105efcf996f13dfb95adef0c5d02a298a647a522666cf44043575003cc812d5803592ce980406d24d089549e4e2a46640f187d96ce9f27861fa2a292838a2b827fca085b1a94e4c04c53fb0679afd6454ba073a8e653aa9460148849328c9c3c49909793b12fa95986fc1c41d8ac5bdc520a8398ac3172687d8348ad457b85654879afcd0a71bfc79889c155b382469ad43213f331c8c489a38855f4844e06a22c0ce50849e05312f5ab2d2997975a8772dd4438074ce5905bb558deca9adb57aea285e920a5deb0979b460b8b996fca7e2068d581e4b2c78994906dd8ff3fb1ba5950b4fb68336f7a7973c5b07c6108590bca31e983da8365f5a43bd347ce4c9f77e5d3be2c3bd398460d9905555a8ef0482df7c8f36b593b25b7b2678c5c65745804ba7fece546bf4db877b7b1734cbd038e4fee1eff219f380ad7f85754b6dbf9c903828a81879e7892d38d3f225e31715a0530f3329364090f9888128fb7ef26111385fce78f28a4e09939a71e49417356890968c8a05240d363c16a331268c64640b94df3a8f5340eadf5bbb2818a2a5802dd8d8a7a973ae01f24793d05a11f960a06292f2be48a37fd8d6dbb7c5b5705615eb7540022db1405821612b7eeb6ee4ca5dc649111ad7ca46941a8a3e0c0ee7e122cf3ce90bd5916c9a08ea0c0e4ae7842165a36c4559e7454ddd172cb130f48a229e883c37d548955f4267f6af62f4a06b9f9e9ce5282959a3454fdd25046ed1b39f052e010bbd120a86147aaa84b627ab733e0690a05854c7a847a706cac0f1ce9f29dd5811f47d3607640691a9579fc33f7c738c39dcae83997188970ac6f550541020905b32d5d998f65699e7830992355ac3d6306e5d4120b9bc9ec04092213a833b87ccde585c14c75b434e463b1648f09927c8cb4d5f236966d49033691ebd1efbdf5a5b43eee2d4da7c9ac2ffaf6de029296d501a72f24a272c2b172469546caabec9818bf14600e879427b006ca8fe6a82f2b7d6c9472b1dc281f8601643f09c968b1db481d2c7241af1f51f7d35a2ffcb7b1bcc11299efdc13e329f780cb1dfb3d28c72b614b6b9bae800b7cd9a8da82d01bfcb409fc14e04f8dc603aa9fff7c343e7af314fc8adf3e809fa78b50ba7c1eafe86de53ace18ffc413b6f0223239dc8fd5285f1f6305c4e5e6eaae12ff7deb7ffdebfe22e2e8ebd6ff8d7afdf051fdfff6f5aefeff2ad7170b27948c224d66ff4d7eed71309bf86180830d33d8bcf479004d36dc43f7ad1a2d1d8334338b2748e480c3b234088374ada0165e20a7f3d6921a56a80233c41b032975c501c7fd79cc3eda6d4a929b5415d0042207f78369c0271f6df6edb7382891f2356c60de4b6a095fc62240dab62e11446a24e50a52c912435912ea91efe669ba14cd8303dccfb35bd78f1707a4764debed12564efe8eaf57713221696f1425a9ac212b1f6a28e590af9931b21dee4612dfcb70ce25c45a9a5659407f2f42a3979da11abbcd6625936871ff8da807288c322e0a69e7f1822fb18b7f05ee3eb2fd09bc6eddefcec0ddf9cf0ffaedd6e965db5d7cfdfeef61bdf1e6d1fbffb7afdfecceffaf715187d39cde4bddee6d99735c365968547d5db6585073c2f43f55f524412409d7b2f6f7d999ca0e514dd658bdf6f1155589baceaab153dd9a90efc569e09c127b1f471185123cb7a2354ac0c52d52467a3fae6b0b22bccfae65d6ce3f7b8b65880aebe79f7f9e4d542c91fd692a1074a489f80a79f4be22cc65ada1555bba1bdd6afae88580bfe82ac58b8ae6159dc3e537eb76ed8c5ea9d56ad3706d8faaf4fadf8847a0f9bbe1439a2a7428ca4c823285cc59b866f22d20bdabdddf7cb74f70c53be11b5bdcad89f12ca106b03d02b89935cd2cdf0ba9e96dbe6c2c58de572acfaa8c1a313adca2690d76b347d54dc5ec5752566fc345dbd503baa0af0695845405d0c70f2c0fc7e65b04fa44d3cbe82b525b7f4e014f06de2d75e553ac2a7a5919a16870b4c6b2803405bc16b9f2ac965b943b976efcabd1cfc8a2be5fd850435019233f942c3b4876e8280dd1d00fbe9750dd232954fe72e2982ddd47af3ba0b15caf96ea93c85adf319f514c3d54979526604ee6dcbfd3ef0056b094aa1e89f1124562ea2c65b94c5d887d362081e5bcea311550eaa5c63efb7b4629158149c1f2661a6da75d0db6bb76d7eeda5d7fa0eb5f668f93da00340000").unwrap()),
+("assets/splitter-main.zip", hex::decode("504b0304140000000000000021560000000000000000000000000e00000073706c69747465722d6d61696e2f504b0304140000000000000021560000000000000000000000001500000073706c69747465722d6d61696e2f6164646f6e732f504b0304140000000000000021560000000000000000000000001e00000073706c69747465722d6d61696e2f6164646f6e732f73706c69747465722f504b0304140000000800000021560cc7f8660d0000000b0000001700000073706c69747465722d6d61696e2f524541444d452e6d645356282ec8c92c29492de20200504b0304140000000800000021566afce2e413000000110000001b00000073706c69747465722d6d61696e2f70726f6a6563742e676f646f744bcecf4bcb4c8f2f4b2d2acecccfb335e50200504b0304140000000800000021565bcbf7e52b0000002d0000002800000073706c69747465722d6d61696e2f6164646f6e732f73706c69747465722f706c7567696e2e6366678b2ec8294dcfcc8be5e2ca4bcc4db5550a2ec8c92c29492d52e22a4e2eca2c28b1558228d04b4f51e20200504b030414000000080000002156407611bf1d0000001b0000002700000073706c69747465722d6d61696e2f6164646f6e732f73706c69747465722f706c7567696e2e67647328c9cfcfe14aad2849cd4b2956704dc92cc92f0ac8294dcfcce30200504b030414000000080000002156665386d414000000120000002900000073706c69747465722d6d61696e2f6164646f6e732f73706c69747465722f73706c69747465722e67644bad2849cd4b295670cecf2b49cccc4b2de20200504b01021403140000000000000021560000000000000000000000000e000000000000000000000080010000000073706c69747465722d6d61696e2f504b010214031400000000000000215600000000000000000000000015000000000000000000000080012c00000073706c69747465722d6d61696e2f6164646f6e732f504b01021403140000000000000021560000000000000000000000001e000000000000000000000080015f00000073706c69747465722d6d61696e2f6164646f6e732f73706c69747465722f504b01021403140000000800000021560cc7f8660d0000000b00000017000000000000000000000080019b00000073706c69747465722d6d61696e2f524541444d452e6d64504b01021403140000000800000021566afce2e413000000110000001b00000000000000000000008001dd00000073706c69747465722d6d61696e2f70726f6a6563742e676f646f74504b01021403140000000800000021565bcbf7e52b0000002d00000028000000000000000000000080012901000073706c69747465722d6d61696e2f6164646f6e732f73706c69747465722f706c7567696e2e636667504b0102140314000000080000002156407611bf1d0000001b00000027000000000000000000000080019a01000073706c69747465722d6d61696e2f6164646f6e732f73706c69747465722f706c7567696e2e6764504b0102140314000000080000002156665386d414000000120000002900000000000000000000008001fc01000073706c69747465722d6d61696e2f6164646f6e732f73706c69747465722f73706c69747465722e6764504b050600000000080008005b020000570200000000").unwrap()),
+("assets/splitter-bare.zip", hex::decode("504b0304140000000000000021565bcbf7e52d0000002d0000001a0000006164646f6e732f73706c69747465722f706c7567696e2e6366675b706c7567696e5d0a0a6e616d653d2253706c6974746572220a7363726970743d22706c7567696e2e6764220a504b030414000000000000002156407611bf1b0000001b000000190000006164646f6e732f73706c69747465722f706c7567696e2e676440746f6f6c0a657874656e647320456469746f72506c7567696e0a504b030414000000000000002156665386d412000000120000001b0000006164646f6e732f73706c69747465722f73706c69747465722e6764657874656e647320436f6e7461696e65720a504b01021403140000000000000021565bcbf7e52d0000002d0000001a00000000000000000000008001000000006164646f6e732f73706c69747465722f706c7567696e2e636667504b0102140314000000000000002156407611bf1b0000001b0000001900000000000000000000008001650000006164646f6e732f73706c69747465722f706c7567696e2e6764504b0102140314000000000000002156665386d412000000120000001b00000000000000000000008001b70000006164646f6e732f73706c69747465722f73706c69747465722e6764504b05060000000003000300d8000000020100000000").unwrap()),
+("evil/-/traversal.tgz", hex::decode("1f8b0800f9f8d46a02ffedd3410ac2301040d1ac3d454e10a79ad65b7887d00c45c41a6cc41ebfad0842f706a4ff313021dbe1a7d05e43a7fb5bb8f4ae8be61764d678ffdeb3f516a9eaef7bf9afa43e7a63c514f01c7278586b364ac7ac7d1cecf91e7567b035e9d3bf73cbe8d086a4d1e53197ec5f9a55ff07694ef45fe4feaf5e23e1030000000000000000000000fcad09701aff8300280000").unwrap()),
+("evil/-/absolute.tgz", hex::decode("1f8b0800f9f8d46a02ffedd3410ac2301046e1ac3d452e609360d3637887d00c45243198883dbed58dd0bd05e9fb3633fcfb57c2780d9398142eb99ba2fa05bb18fafe7317eb6badf3dfffbd3beb4fbdd2566de0515bb86bad764ae62639567dbe453928ec8d69a998a9a4a3d43114895d9bdbe6fddb61d5bff3cb44ff1b28cf2c91f0010000000000000000000000fed60b4369be4700280000").unwrap()),
+("evil/-/symlink.tgz", hex::decode("1f8b0800f9f8d46a02ffedd44d0a83301086e1ac7b8a9c20c6f8778bde219820629b4a4da9c7af9542c14d57068aef436086d964f13133da76b09dcfaeb60faa73620f7a5197e55a17dbaa755e7dfbf73cd755510aa945028f29dabb94e2a0fc1c7d70933cdf9c3f091ccdf8d9ff4b1f86bdfef8b9ffdb3ed74d6184344a65eb63ff93e49ff9a9b5a3772ace316dfef5267f533435f73f49fecfe01d871f0000000000000000000080bff60203c21d1100280000").unwrap()),
+("evil/-/chained-symlink.tgz", hex::decode("1f8b0800f9f8d46a02ffedd54d0ac2301086e1ac3d454e901fdbe618de2136a188588b6dd1e3db5641e846101290becf66866c66f1cd90ced767df447df1a7563541a46026ae2c973a5957636cf5e9e7776baaa214d2880cc67ef03729c546c5c710dbd0cbc335c49dc0d674effb0fba1f8f89667cbdff756f4de10a21f74a71ff99f2f70967fc90bf3373fecb52eab45b40feaffc75ec6bdfc5a086c7903d7fb7cadf3a67f9ffb3e47f6f63e0e30700000000000000000080bff704fcdd3f6f00280000").unwrap()),
+("evil/-/hardlink.tgz", hex::decode("1f8b0800f9f8d46a02ffedd3410ac2301085e1ac3d454e902635d55b7887d00c55c41ada083d7ea3084a37aeeaa6ffc7c03c6633ab97427b0d9d54b770e94d17d51a6c71f0feb58be5b6d6359ffcbc3bdbecbdd256fdc163cc61d05a6d944c59fa38ead33dca4e616bd2bbffe730c4b57efcecff32bbfa583ba59d31551919db90249a3c65fa0f0000000000000000000000007c9b010f4df48800280000").unwrap()),
+("evil/-/links.tgz", hex::decode("1f8b0800f9f8d46a02ffedd54d0e82301086e1597b8a9e40fb031ec33b4c6c8344450298787cd0981871e10a8ce17d3633e9f6cb7cad757fd4226dce5a56eb22ca14ec609b658f39184f6b5dfedaefefcee62113636506d7b6d3c61859a874eb52155bb3bbc4b4122c4dfdbc7f3d95da4e54005fef7fbc3bef4210e3a72c25eeff2dff8336f167fdff917f08b917e3ea193ea785e70f0000000000000000000000fe5f0f9579792a00280000").unwrap()),
+("evil/-/traversal.zip", hex::decode("504b0304140000000000000021560a19f06e0d0000000d0000000c0000006576696c2f6d61696e2e6764657874656e6473204e6f64650a504b030414000000000000002156fb5eb3850600000006000000160000006576696c2f2e2e2f2e2e2f657363617065642e74787470776e65640a504b01021403140000000000000021560a19f06e0d0000000d0000000c00000000000000000000008001000000006576696c2f6d61696e2e6764504b0102140314000000000000002156fb5eb38506000000060000001600000000000000000000008001370000006576696c2f2e2e2f2e2e2f657363617065642e747874504b050600000000020002007e000000710000000000").unwrap()),
+("@bendn/plugin/-/plugin-1.0.0.tgz", hex::decode("1f8b080059f9d46a02ffedd7414b84401c8761cff32986e91a3ae68c41b0b0411e8ba56b743067126b9d11c78d20faeeb9ab117859029b60fd3d0747f4e0415ffddbe4c56b5eeaa819d6f0c55913cc8cf752210e6b6fba722e939ffdfdf13896a908280f3cd8b92e6f290d16ea8399bcd6ec8aad9fb451266ab6bbb232ec9cbde9d655d6f467e290879c7d92004ecfd87d749f5ddfdc6661adfee01a47fb8fe349ff5c8a4bf4efc3191d8a47ddcbee3f57ca1a37befec7252c9ecbfff9fe0b7e91a07f1f1e865bfd48c87e0e58b1cdf0fd27ae68aba65bb1f1492815c31b6271fd97f34c0347fb4fa6fd27a9c4fcefc5bab3764bf47bd74fff8e66aaea6cbbc144b0b8fe95aeed613357f4bf9affe5f4ff5fa41cfdfbf05dfe9d551ac5030000000000000000009c8e2ff0a2e3bc00280000").unwrap()),
+("@bendn/filtered/-/filtered-1.0.0.tgz", hex::decode("1f8b0800aff9d46a02ffed98b14ec330108633f314915991734e9a207502898e30b0561dd2e61a191a3b8a034242bc3b2640872c882ab842fdbfe1ceb1870ca7ef2e715b6e1ecb9a93f633cb07674d3431e42966b3217bc699944af7eb615fa9bca028a628004fae2fbb388e4e945761ca86c55c5cadd95426d9ea5dcf1d57e2423c73e7b435fe4c4992e477fc213b315f8a9d5e27fef97e717d73bb904d25566f6711f8877c799fec4bf907eff8c97f52d9c87fca7305ff43701e7f1b0f814fd9ff8f8ede94dac8ba3a82fff978fe6794c2ff10f04befe7be8bef6cc5e800a7edbfde58235b534bddb4b6eb03fa4f23ff33a219fc0fc1b2e3a66c57501ffe2715377608137f04fc7efea7695ac07fcc7f10d47fbb7189775fd7c6761cd0fff15aa57991c1ffa3d4df87a9ff010fe8ff94a3fee8ff2098ff3dbbde0d71fa1ba003ee7f2e33dcffc27f000000000000c0a4bc03af7a1e4200280000").unwrap()),
     ]);
 }